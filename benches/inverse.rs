@@ -0,0 +1,57 @@
+//! Compares the closed-form `Mat2`/`Mat3`/`Mat4` inverses against the generic Gauss-Jordan path.
+//!
+//! Run with `cargo bench --bench inverse`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+use vmm::*;
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench<R>(mut f: impl FnMut() -> R) -> Duration
+{
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS
+    {
+        black_box(f());
+    }
+
+    start.elapsed()
+}
+
+fn report(name: &str, generic: Duration, closed_form: Duration)
+{
+    println!(
+        "{:<12} generic: {:>10.2?}  closed form: {:>10.2?}  speedup: {:.2}x",
+        name,
+        generic,
+        closed_form,
+        generic.as_secs_f64() / closed_form.as_secs_f64()
+    );
+}
+
+fn main()
+{
+    let mat2 = mat2_raw![[4.0_f64, 7.0], [2.0, 6.0]];
+    let mat3 = mat3_raw![[1.0_f64, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+    let mat4 = Mat4::<f64>::identity()
+        .translate(&vec3![1.0, -2.0, 3.0])
+        .rotate(0.7, &vec3![0.0, 1.0, 0.0])
+        .scale(&vec3![2.0, 0.5, 4.0]);
+
+    report(
+        "inverse 2x2",
+        bench(|| black_box(mat2).inverse_gauss_jordan()),
+        bench(|| black_box(mat2).inverse()),
+    );
+    report(
+        "inverse 3x3",
+        bench(|| black_box(mat3).inverse_gauss_jordan()),
+        bench(|| black_box(mat3).inverse()),
+    );
+    report(
+        "inverse 4x4",
+        bench(|| black_box(mat4).inverse_gauss_jordan()),
+        bench(|| black_box(mat4).inverse()),
+    );
+}
\ No newline at end of file
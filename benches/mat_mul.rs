@@ -0,0 +1,69 @@
+//! Compares `MatN` multiplication against the textbook `i, j, k` loop.
+//!
+//! Run with `cargo bench --bench mat_mul`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+use vmm::*;
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench<R>(mut f: impl FnMut() -> R) -> Duration
+{
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS
+    {
+        black_box(f());
+    }
+
+    start.elapsed()
+}
+
+fn report(name: &str, naive: Duration, current: Duration)
+{
+    println!(
+        "{:<12} naive: {:>10.2?}  current: {:>10.2?}  speedup: {:.2}x",
+        name,
+        naive,
+        current,
+        naive.as_secs_f64() / current.as_secs_f64()
+    );
+}
+
+fn naive_mul<const N: usize>(lhs: &MatN<f64, N>, rhs: &MatN<f64, N>) -> MatN<f64, N>
+{
+    let mut result = MatN::new();
+
+    for i in 0..N {
+        for j in 0..N {
+            for k in 0..N
+            {
+                result[i][j] += lhs[i][k] * rhs[k][j];
+            }
+        }
+    }
+
+    result
+}
+
+fn compare<const N: usize>(name: &str)
+{
+    let a = MatN::<f64, N>::from_fn(|i, j| (i as f64 * 0.37 - j as f64).sin());
+    let b = MatN::<f64, N>::from_fn(|i, j| (i as f64 + j as f64 * 1.3).cos());
+
+    assert_eq!(a * b, naive_mul(&a, &b));
+
+    report(
+        name,
+        bench(|| naive_mul(&black_box(a), &black_box(b))),
+        bench(|| black_box(a) * black_box(b)),
+    );
+}
+
+fn main()
+{
+    compare::<4>("mul 4x4");
+    compare::<8>("mul 8x8");
+    compare::<16>("mul 16x16");
+    compare::<32>("mul 32x32");
+}
\ No newline at end of file
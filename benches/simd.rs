@@ -0,0 +1,92 @@
+//! Compares the `simd` paths of `Vec4<f32>`/`Mat4<f32>` against plain scalar loops.
+//!
+//! Run with `cargo bench --features simd --bench simd`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+use vmm::*;
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn bench<R>(mut f: impl FnMut() -> R) -> Duration
+{
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS
+    {
+        black_box(f());
+    }
+
+    start.elapsed()
+}
+
+fn report(name: &str, scalar: Duration, simd: Duration)
+{
+    println!(
+        "{:<12} scalar: {:>10.2?}  simd: {:>10.2?}  speedup: {:.2}x",
+        name,
+        scalar,
+        simd,
+        scalar.as_secs_f64() / simd.as_secs_f64()
+    );
+}
+
+fn scalar_add(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4]
+{
+    let mut result = *a;
+
+    for (val, other) in result.iter_mut().zip(b.iter())
+    {
+        *val += *other;
+    }
+
+    result
+}
+
+fn scalar_dot(a: &[f32; 4], b: &[f32; 4]) -> f32
+{
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn scalar_mul_mat_vec(mat: &[[f32; 4]; 4], vec: &[f32; 4]) -> [f32; 4]
+{
+    let mut result = [0.0; 4];
+
+    for (i, row) in mat.iter().enumerate()
+    {
+        for (j, val) in row.iter().enumerate()
+        {
+            result[i] += vec[j] * val;
+        }
+    }
+
+    result
+}
+
+fn main()
+{
+    let a = vec4![1.0_f32, 2.0, 3.0, 4.0];
+    let b = vec4![0.5_f32, -1.5, 2.5, -3.5];
+    let mat = mat4_raw![
+        [1.0_f32, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0]
+    ];
+    let raw_mat = mat.to_mat();
+
+    report(
+        "add",
+        bench(|| scalar_add(black_box(a.to_arr()), black_box(b.to_arr()))),
+        bench(|| black_box(a) + black_box(b)),
+    );
+    report(
+        "dot",
+        bench(|| scalar_dot(black_box(a.to_arr()), black_box(b.to_arr()))),
+        bench(|| black_box(a).dot(black_box(&b))),
+    );
+    report(
+        "mul_mat_vec",
+        bench(|| scalar_mul_mat_vec(black_box(&raw_mat), black_box(a.to_arr()))),
+        bench(|| black_box(mat).mul_mat_vec(black_box(&a))),
+    );
+}
\ No newline at end of file
@@ -2,7 +2,32 @@ pub mod vectors;
 pub mod matrices;
 pub mod macros;
 pub mod math; 
+#[cfg(feature = "bytemuck")]
 pub mod bytemuck_impl;
+#[cfg(feature = "rand")]
+pub mod rand_impl;
+#[cfg(feature = "glam")]
+pub mod glam_impl;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+
+/// Bound put on `T` by the operators that may dispatch to the `simd` module.
+///
+/// The SIMD paths compare `TypeId`s, so with the `simd` feature on `x86_64` the trait requires
+/// `T: 'static`. Otherwise it is implemented for every type, leaving the public bounds unchanged.
+mod scalar
+{
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    pub trait SimdScalar: 'static {}
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    impl<T: 'static> SimdScalar for T {}
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    pub trait SimdScalar {}
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    impl<T> SimdScalar for T {}
+}
+pub(crate) use scalar::SimdScalar;
 
 pub use vectors::*;
 pub use matrices::*;
@@ -1,24 +1,131 @@
-/// This is file aims to integrate the types with the crate [bytemuck](https://crates.io/crates/bytemuck/)
+//! This file aims to integrate the types with the crate [bytemuck](https://crates.io/crates/bytemuck/)
+//!
+//! `VecN` and `MatN` are `#[repr(transparent)]` over arrays of `T`, so they are `Zeroable`/`Pod`
+//! exactly when `T` is. `NoUninit` and `AnyBitPattern` come from the blanket impls of `bytemuck`
+//! for every `Pod` type.
+//!
+//! # Examples
+//!
+//! ```
+//! # use vmm::*;
+//! let vec = vec4![1.0_f32, -2.5, 3.0, 0.125];
+//! let bytes: &[u8] = bytemuck::bytes_of(&vec);
+//!
+//! assert_eq!(bytes.len(), 16);
+//! assert_eq!(&bytes[..4], &1.0_f32.to_ne_bytes());
+//! assert_eq!(bytemuck::pod_read_unaligned::<Vec4<f32>>(bytes), vec);
+//!
+//! let mat = Mat2::<u16>::from_mat(&[[1, 2], [3, 4]]);
+//! let elements: &[u16] = bytemuck::cast_slice(std::slice::from_ref(&mat));
+//!
+//! assert_eq!(elements, &[1, 2, 3, 4]);
+//! ```
+//!
+//! Element types that are not `Pod`, like `bool`, can't be cast:
+//!
+//! ```compile_fail
+//! # use vmm::*;
+//! let vec = vec2![true, false];
+//! let bytes: &[u8] = bytemuck::bytes_of(&vec);
+//! ```
 
-use std::marker;
 use bytemuck::{Pod, Zeroable};
 use super::{VecN, MatN};
 
 unsafe impl<T, const N: usize> Zeroable for VecN<T, N> 
 where 
-    T: Default + marker::Copy,
-    f64: From<T> {}
+    T: Default + Copy + Zeroable {}
 
 unsafe impl<T, const N: usize> Pod for VecN<T, N> 
 where
-    T: Default + marker::Copy + 'static,
-    f64: From<T> {}
+    T: Default + Copy + Pod {}
 
 unsafe impl<T, const N: usize> Zeroable for MatN<T, N> 
 where 
-    T: Default + marker::Copy,
-    f64: From<T> {}
+    T: Default + Copy + Zeroable {}
 unsafe impl<T, const N: usize> Pod for MatN<T, N>
 where
-    T: Default + marker::Copy + 'static,
-    f64: From<T> {}
\ No newline at end of file
+    T: Default + Copy + Pod {}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy + Pod,
+{
+    /// Returns the memory of the vector as a slice of bytes, ie to upload it into a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec2![1u16, 2];
+    ///
+    /// assert_eq!(vec.as_bytes().len(), 4);
+    /// assert_eq!(Vec2::<u16>::from_bytes(vec.as_bytes()), Some(vec));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8]
+    {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Reads a vector from a slice of bytes, with any alignment.
+    ///
+    /// # Returns
+    ///
+    /// The vector, or `None` if the length of `bytes` is not the size of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let bytes = [0u8; 12];
+    ///
+    /// assert_eq!(Vec3::<f32>::from_bytes(&bytes), Some(vec3![0.0, 0.0, 0.0]));
+    /// assert_eq!(Vec3::<f32>::from_bytes(&bytes[..8]), None);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self>
+    {
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+}
+
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy + Pod,
+{
+    /// Returns the memory of the matrix as a slice of bytes, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::<f32>::identity().translate(&vec3![1.0, 2.0, 3.0]);
+    /// let bytes = mat.as_bytes();
+    ///
+    /// assert_eq!(bytes.len(), 64);
+    /// assert_eq!(Mat4::<f32>::from_bytes(bytes), Some(mat));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8]
+    {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Reads a matrix from a slice of bytes in row-major order, with any alignment.
+    ///
+    /// # Returns
+    ///
+    /// The matrix, or `None` if the length of `bytes` is not the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let bytes = [1u8, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(Mat2::<u8>::from_bytes(&bytes[1..]), Some(mat2_raw![[2, 3], [4, 5]]));
+    /// assert_eq!(Mat2::<u8>::from_bytes(&bytes), None);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self>
+    {
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+}
\ No newline at end of file
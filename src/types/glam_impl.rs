@@ -0,0 +1,89 @@
+//! This file aims to integrate the types with the crate [glam](https://crates.io/crates/glam), it is only
+//! compiled with the `glam` feature enabled.
+//!
+//! Vectors convert directly, since both crates store the components in the same order.
+//!
+//! `MatN` stores its rows, while `glam` stores the columns of a matrix, the conversions transpose the
+//! data so the same transform is represented on both sides. In other words, `mat[i][j]` is the
+//! element at row `i` and column `j`, which is `glam_mat.col(j)[i]`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use vmm::*;
+//! let glam_vec = glam::Vec3::new(1.0, 2.0, 3.0);
+//! let vec: Vec3<f32> = glam_vec.into();
+//!
+//! assert_eq!(vec, vec3![1.0, 2.0, 3.0]);
+//! assert_eq!(glam::Vec3::from(vec), glam_vec);
+//!
+//! let translation = Mat4::<f32>::identity().translate(&vec3![1.0, 2.0, 3.0]);
+//! let glam_mat = glam::Mat4::from(translation);
+//!
+//! assert_eq!(glam_mat, glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0)));
+//! assert_eq!(Mat4::from(glam_mat), translation);
+//! ```
+
+use super::{VecN, MatN};
+
+macro_rules! impl_glam_vec 
+{
+    ($glam:ty, $t:ty, $n:literal) => 
+    {
+        impl From<$glam> for VecN<$t, $n>
+        {
+            fn from(value: $glam) -> Self 
+            {
+                Self::from_array(&value.to_array())
+            }
+        }
+        impl From<VecN<$t, $n>> for $glam
+        {
+            fn from(value: VecN<$t, $n>) -> Self 
+            {
+                <$glam>::from_array(*value.to_arr())
+            }
+        }
+    };
+}
+
+macro_rules! impl_glam_mat 
+{
+    ($glam:ty, $t:ty, $n:literal) => 
+    {
+        impl From<$glam> for MatN<$t, $n>
+        {
+            fn from(value: $glam) -> Self 
+            {
+                Self::from_mat(&value.transpose().to_cols_array_2d())
+            }
+        }
+        impl From<MatN<$t, $n>> for $glam
+        {
+            fn from(value: MatN<$t, $n>) -> Self 
+            {
+                <$glam>::from_cols_array_2d(&value.transpose().to_mat())
+            }
+        }
+    };
+}
+
+impl_glam_vec!(glam::Vec2, f32, 2);
+impl_glam_vec!(glam::Vec3, f32, 3);
+impl_glam_vec!(glam::Vec4, f32, 4);
+impl_glam_vec!(glam::DVec2, f64, 2);
+impl_glam_vec!(glam::DVec3, f64, 3);
+impl_glam_vec!(glam::DVec4, f64, 4);
+impl_glam_vec!(glam::IVec2, i32, 2);
+impl_glam_vec!(glam::IVec3, i32, 3);
+impl_glam_vec!(glam::IVec4, i32, 4);
+impl_glam_vec!(glam::UVec2, u32, 2);
+impl_glam_vec!(glam::UVec3, u32, 3);
+impl_glam_vec!(glam::UVec4, u32, 4);
+
+impl_glam_mat!(glam::Mat2, f32, 2);
+impl_glam_mat!(glam::Mat3, f32, 3);
+impl_glam_mat!(glam::Mat4, f32, 4);
+impl_glam_mat!(glam::DMat2, f64, 2);
+impl_glam_mat!(glam::DMat3, f64, 3);
+impl_glam_mat!(glam::DMat4, f64, 4);
\ No newline at end of file
@@ -136,6 +136,57 @@ impl Sqrrt for f64 {
     } 
 }
 
+pub trait Fma {
+    /// Computes `self * a + b` with a single rounding, ie a fused multiply-add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(2.0f64.mul_addd(3.0, 1.0), 7.0);
+    /// assert_eq!(0.5f32.mul_addd(4.0, -2.0), 0.0);
+    /// ```
+    fn mul_addd(&self, a: Self, b: Self) -> Self;
+}
+impl Fma for f32 {
+    fn mul_addd(&self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+}
+impl Fma for f64 {
+    fn mul_addd(&self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+}
+
+pub trait FloatPow {
+    /// Raises self to the floating point power `exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(2.0f64.powff(3.0), 8.0);
+    /// assert_eq!(4.0f32.powff(0.5), 2.0);
+    /// assert!((-8.0f64).powff(1.0 / 3.0).is_nan());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - A negative base with a fractional exponent results in `NaN`.
+    fn powff(&self, exp: f64) -> Self;
+}
+impl FloatPow for f32 {
+    fn powff(&self, exp: f64) -> Self {
+        self.powf(exp as f32)
+    }
+}
+impl FloatPow for f64 {
+    fn powff(&self, exp: f64) -> Self {
+        self.powf(exp)
+    }
+}
+
 pub trait UnitValue {
     fn unit_value() -> Self;
 }
@@ -210,9 +261,251 @@ impl UnitValue for f64 {
     } 
 }
 
+pub trait FromF64 {
+    /// Converts a `f64` into `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(i32::from_f64(2.7), 2);
+    /// assert_eq!(f32::from_f64(0.5), 0.5);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Behaves like an `as` cast, so integer types truncate toward zero and saturate at their bounds.
+    fn from_f64(value: f64) -> Self;
+}
+impl FromF64 for i8 {
+    fn from_f64(value: f64) -> Self {
+        value as i8
+    } 
+}
+impl FromF64 for i16 {
+    fn from_f64(value: f64) -> Self {
+        value as i16
+    } 
+}
+impl FromF64 for i32 {
+    fn from_f64(value: f64) -> Self {
+        value as i32
+    } 
+}
+impl FromF64 for i64 {
+    fn from_f64(value: f64) -> Self {
+        value as i64
+    } 
+}
+impl FromF64 for i128 {
+    fn from_f64(value: f64) -> Self {
+        value as i128
+    } 
+}
+impl FromF64 for isize {
+    fn from_f64(value: f64) -> Self {
+        value as isize
+    } 
+}
+impl FromF64 for u8 {
+    fn from_f64(value: f64) -> Self {
+        value as u8
+    } 
+}
+impl FromF64 for u16 {
+    fn from_f64(value: f64) -> Self {
+        value as u16
+    } 
+}
+impl FromF64 for u32 {
+    fn from_f64(value: f64) -> Self {
+        value as u32
+    } 
+}
+impl FromF64 for u64 {
+    fn from_f64(value: f64) -> Self {
+        value as u64
+    } 
+}
+impl FromF64 for u128 {
+    fn from_f64(value: f64) -> Self {
+        value as u128
+    } 
+}
+impl FromF64 for usize {
+    fn from_f64(value: f64) -> Self {
+        value as usize
+    } 
+}
+impl FromF64 for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    } 
+}
+impl FromF64 for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    } 
+}
+
+pub trait ExactDiv: Sized {
+    /// Divides `self` by `rhs`, only when the quotient is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(12i32.exact_div(4), Some(3));
+    /// assert_eq!(7i32.exact_div(2), None);
+    /// assert_eq!(7.0f64.exact_div(2.0), Some(3.5));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Floats always return `Some`, integers return `None` when there is a remainder, `rhs` is
+    ///   zero or the division overflows.
+    fn exact_div(self, rhs: Self) -> Option<Self>;
+}
+impl ExactDiv for f32 {
+    fn exact_div(self, rhs: Self) -> Option<Self> {
+        Some(self / rhs)
+    }
+}
+impl ExactDiv for f64 {
+    fn exact_div(self, rhs: Self) -> Option<Self> {
+        Some(self / rhs)
+    }
+}
+
+pub trait Epsilon {
+    /// Returns the relative rounding error of the type, used to tell a rounding residue from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(f64::epsilon(), f64::EPSILON);
+    /// assert_eq!(i32::epsilon(), 0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Integer arithmetic is exact, so integer types return `0`.
+    fn epsilon() -> Self;
+}
+impl Epsilon for f32 {
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+}
+impl Epsilon for f64 {
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+}
+
+/// Implements `ExactDiv` and `Epsilon` for integer types.
+macro_rules! impl_exact_int {
+    ($($int:ty),*) => {
+        $(
+            impl ExactDiv for $int {
+                fn exact_div(self, rhs: Self) -> Option<Self> {
+                    if self.checked_rem(rhs)? == 0 { self.checked_div(rhs) } else { None }
+                }
+            }
+            impl Epsilon for $int {
+                fn epsilon() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+impl_exact_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+pub trait NumCast<U> {
+    /// Converts `self` into `U`, like an `as` cast between primitive types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(NumCast::<f32>::num_cast(2.5f64), 2.5f32);
+    /// assert_eq!(NumCast::<i32>::num_cast(-1.9f32), -1);
+    /// assert_eq!(NumCast::<u8>::num_cast(300i32), 44);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Floats are truncated toward zero and saturate at the bounds of integer types,
+    ///   integers wrap when narrowed.
+    fn num_cast(self) -> U;
+}
+
+/// Implements `NumCast` from every type in the first list into every type in the second one.
+macro_rules! impl_num_cast {
+    ([$($from:ty),*], $to:tt) => {
+        $( impl_num_cast!(@from $from, $to); )*
+    };
+    (@from $from:ty, [$($to:ty),*]) => {
+        $(
+            impl NumCast<$to> for $from {
+                fn num_cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+impl_num_cast!(
+    [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64],
+    [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64]
+);
+
 pub trait SinCosTan {
+    /// Computes the cosine of self (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(0i32.coss(), 1);
+    /// assert_eq!(0.0f64.coss(), 1.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Integer types compute in `f64` and truncate the result back to `Self`.
     fn coss(&self) -> Self;
+    /// Computes the sine of self (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(0i32.sinn(), 0);
+    /// assert_eq!(2u8.sinn(), 0);
+    /// assert_eq!(0.0f64.sinn(), 0.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Integer types compute in `f64` and truncate the result back to `Self`.
     fn sinn(&self) -> Self;
+    /// Computes the tangent of self (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(0i32.tann(), 0);
+    /// assert_eq!(2i16.tann(), -2);
+    /// assert_eq!(0.0f64.tann(), 0.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Integer types compute in `f64` and truncate the result back to `Self`.
     fn tann(&self) -> Self;
 }
 impl SinCosTan for i8 {
@@ -220,10 +513,10 @@ impl SinCosTan for i8 {
         (*self as f64).cos() as i8
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as i8
+        (*self as f64).sin() as i8
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as i8
+        (*self as f64).tan() as i8
     }
 }
 impl SinCosTan for i16 {
@@ -231,10 +524,10 @@ impl SinCosTan for i16 {
         (*self as f64).cos() as i16
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as i16
+        (*self as f64).sin() as i16
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as i16
+        (*self as f64).tan() as i16
     }
 }
 impl SinCosTan for i32 {
@@ -242,10 +535,10 @@ impl SinCosTan for i32 {
         (*self as f64).cos() as i32
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as i32
+        (*self as f64).sin() as i32
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as i32
+        (*self as f64).tan() as i32
     }
 }
 impl SinCosTan for i64 {
@@ -253,10 +546,10 @@ impl SinCosTan for i64 {
         (*self as f64).cos() as i64
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as i64
+        (*self as f64).sin() as i64
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as i64
+        (*self as f64).tan() as i64
     }
 }
 impl SinCosTan for u8 {
@@ -264,10 +557,10 @@ impl SinCosTan for u8 {
         (*self as f64).cos() as u8
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as u8
+        (*self as f64).sin() as u8
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as u8
+        (*self as f64).tan() as u8
     }
 }
 impl SinCosTan for u16 {
@@ -275,10 +568,10 @@ impl SinCosTan for u16 {
         (*self as f64).cos() as u16
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as u16
+        (*self as f64).sin() as u16
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as u16
+        (*self as f64).tan() as u16
     }
 }
 impl SinCosTan for u32 {
@@ -286,10 +579,10 @@ impl SinCosTan for u32 {
         (*self as f64).cos() as u32
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as u32
+        (*self as f64).sin() as u32
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as u32
+        (*self as f64).tan() as u32
     }
 }
 impl SinCosTan for u64 {
@@ -297,10 +590,10 @@ impl SinCosTan for u64 {
         (*self as f64).cos() as u64
     }
     fn sinn(&self) -> Self {
-        (*self as f64).cos() as u64
+        (*self as f64).sin() as u64
     } 
     fn tann(&self) -> Self {
-        (*self as f64).cos() as u64
+        (*self as f64).tan() as u64
     }
 }
 impl SinCosTan for f32 {
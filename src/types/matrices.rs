@@ -1,8 +1,12 @@
 pub mod math;
+pub mod mat_mn;
+pub mod linalg;
 pub use math::*;
+pub use mat_mn::*;
 
-use std::ops::{IndexMut, Index, Add, Sub, Mul};
-use crate::{SinCosTan, UnitValue, Vec2, Vec3, VecN};
+use std::ops::{IndexMut, Index, Add, Sub, Mul, Neg};
+use crate::{FromF64, ScalarMath, SinCosTan, UnitValue, Vec2, Vec3, VecError, VecN};
+use super::SimdScalar;
 
 /// Generic object representing a mathematical square matrix, with elements of type `T` and a fixed size `N`.
 ///
@@ -25,12 +29,15 @@ use crate::{SinCosTan, UnitValue, Vec2, Vec3, VecN};
 /// # Notes
 ///
 /// - Uses the type VecN as its rows.
+/// - `MatN` is `#[repr(transparent)]`, so it has the same memory layout as `[[T; N]; N]`.
+/// - `MatN` implements `Hash` when `T` does.
 ///
 /// # See Also
 /// 
 /// - [`VecN`].
 /// - [`Mat2`], [`Mat3`] and [`Mat4`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct MatN<T, const N: usize>
 where
     T: Default + Copy,
@@ -92,11 +99,82 @@ where
     /// # Notes
     ///
     /// - The size of the matrix is fixed at compile time based on the constant `N`.
-    pub fn new_with(value: T) -> Self
+    /// - This is a `const fn`, so it can be used to build `const` and `static` matrices.
+    ///
+    /// # See Also
+    ///
+    /// - [`splat`](MatN::splat): Alias of this function, named like in other math crates.
+    pub const fn new_with(value: T) -> Self
     {
         Self { data: [VecN::new_with(value); N] } 
     }
 
+    /// Creates a new `MatN` with `value` as the value for each element, alias of [`new_with`](MatN::new_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat2::splat(7);
+    ///
+    /// assert_eq!(mat, MatN::new_with(7));
+    /// assert_eq!(mat.to_mat(), [[7, 7], [7, 7]]);
+    /// ```
+    pub const fn splat(value: T) -> Self
+    {
+        Self::new_with(value)
+    }
+
+    /// Creates a new `MatN` with every element set to zero, ie the default value of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(Mat2::<f64>::zeros().to_mat(), [[0.0, 0.0], [0.0, 0.0]]);
+    /// ```
+    pub fn zeros() -> Self
+    {
+        Self::new()
+    }
+
+    /// Creates a new `MatN` with every element set to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(Mat2::<i32>::ones().to_mat(), [[1, 1], [1, 1]]);
+    /// ```
+    pub fn ones() -> Self
+    where
+        T: UnitValue,
+    {
+        Self::new_with(T::unit_value())
+    }
+
+    /// Creates a new `MatN` by calling `f` with the `(row, column)` of each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function returning the element at each `(row, column)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat3::from_fn(|i, j| (i * 3 + j) as i32);
+    ///
+    /// assert_eq!(mat.to_mat(), [[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+    /// assert_eq!(Mat4::from_fn(|i, j| if i == j { 1.0 } else { 0.0 }), Mat4::identity());
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self { data: std::array::from_fn(|i| VecN::from_fn(|j| f(i, j))) }
+    }
+
     /// This function constructs a new matrix of fixed size `N` using the elements from the provided
     /// array of `VecN` reference `data`.
     ///
@@ -116,12 +194,17 @@ where
     /// let mat = MatN::from_mat_vec(&array);
     /// 
     /// assert_eq!(mat.to_mat_vec(), &array);
+    ///
+    /// const FLIP_Y: Mat2<f32> = mat2![vec2![1.0, 0.0], vec2![0.0, -1.0]];
+    ///
+    /// assert_eq!(FLIP_Y.mul_mat_vec(&vec2![2.0, 3.0]), vec2![2.0, -3.0]);
     /// ```
     ///
     /// # notes
     ///
     /// - the size of the matrix is fixed at compile time based on the constant `N`.
-    pub fn from_mat_vec(data: &[VecN<T, N>; N]) -> Self
+    /// - This is a `const fn`, so it can be used to build `const` and `static` matrices.
+    pub const fn from_mat_vec(data: &[VecN<T, N>; N]) -> Self
     {
         Self { data: *data }
     }
@@ -212,17 +295,38 @@ where
     /// # Notes
     /// 
     /// - More expensive than `to_mat_vec`.
+    /// - The array is row-major, ie `to_mat()[i]` is the row `i`. Use
+    ///   [`to_mat_col_major`](MatN::to_mat_col_major) for APIs that expect columns, like OpenGL.
     pub fn to_mat(&self) -> [[T; N]; N]
     {
         let mut result = [[T::default(); N]; N];
         
         for (val, other) in result.iter_mut().zip(self.data.iter())
         {
-            *val = other.to_arr().clone();
+            *val = *other.to_arr();
         }
         
         result
     }
+
+    /// Returns a `copy` of the matrix as a raw 2D array in column-major order.
+    ///
+    /// `to_mat_col_major()[j]` is the column `j`, which is the layout expected by column-major APIs
+    /// like OpenGL and WGSL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::from_translation(&vec3![1, 2, 3]);
+    ///
+    /// assert_eq!(mat.to_mat_col_major()[3], [1, 2, 3, 1]);
+    /// assert_eq!(mat.to_mat_col_major(), mat.transpose().to_mat());
+    /// ```
+    pub fn to_mat_col_major(&self) -> [[T; N]; N]
+    {
+        self.transpose().to_mat()
+    }
     
     /// Fills all elements of `MatN` with `value`.
     ///
@@ -279,7 +383,7 @@ where
     /// - This has a time complexity of `O(n^2)`.
     pub fn transpose(&self) -> Self
     {
-        let mut result = self.clone();
+        let mut result = *self;
 
         for i in 0..N {
             for j in 0..N
@@ -290,6 +394,63 @@ where
 
         result
     }
+
+    /// Transposes the matrix in place, swapping rows with columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let mut mat = mat4_raw![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]];
+    /// let transposed = mat.transpose();
+    /// mat.transpose_mut();
+    ///
+    /// assert_eq!(mat, transposed);
+    /// assert_eq!(mat.to_mat(), [[1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15], [4, 8, 12, 16]]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Only the elements above the main diagonal are visited, each one is swapped with its mirror.
+    /// - Unlike `transpose`, no copy of the matrix is made.
+    pub fn transpose_mut(&mut self)
+    {
+        for i in 0..N {
+            for j in (i + 1)..N
+            {
+                let temp = self[i][j];
+                self[i][j] = self[j][i];
+                self[j][i] = temp;
+            }
+        }
+    }
+
+    /// Computes the Gram matrix of the columns, ie `self.transpose() * self`.
+    ///
+    /// Each element `(i, j)` is the dot product of the columns `i` and `j`, this is the matrix
+    /// of the normal equations `A^T * A * x = A^T * b` used in least-squares fitting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1, 2], [3, 4]];
+    /// assert_eq!(mat.gram().to_mat(), [[10, 14], [14, 20]]);
+    ///
+    /// let rotation = mat2_raw![[0.6, -0.8], [0.8, 0.6]];
+    /// assert!(rotation.gram().is_identity(1e-12));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The Gram matrix is always symmetric.
+    pub fn gram(&self) -> Self
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.transpose() * *self
+    }
     pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, VecN<T, N>>
     {
         self.data.iter()
@@ -298,143 +459,808 @@ where
     {
         self.data.iter_mut()
     }
-}
-
-impl<T, const N: usize> Identity for MatN<T, N>
-where
-    T: Default + Copy + UnitValue,
-{
-    fn identity() -> Self 
-    {
-        let mut result = MatN::new();
-         
-        for i in 0..N
-        {
-            result[i][i] = T::unit_value();
-        }
-        
-        result
-    }
-}
 
-impl<T, const N: usize> Index<usize> for MatN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = VecN<T, N>; 
-    
-    fn index(&self, index: usize) -> &Self::Output 
+    /// Returns an iterator over every element of the matrix, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// assert_eq!(mat.iter_elements().sum::<i32>(), 45);
+    /// assert_eq!(mat.iter_elements().nth(3), Some(&4));
+    /// ```
+    pub fn iter_elements(&self) -> impl Iterator<Item = &T>
     {
-        &self.data[index]     
+        self.data.iter().flat_map(|row| row.iter())
     }
-}
 
-impl<T, const N: usize> IndexMut<usize> for MatN<T, N>
-where
-    T: Default + Copy,
-{
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output 
+    /// Returns a mutable iterator over every element of the matrix, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut mat = mat2_raw![[1, 2], [3, 4]];
+    ///
+    /// for val in mat.iter_elements_mut()
+    /// {
+    ///     *val *= 10;
+    /// }
+    ///
+    /// assert_eq!(mat.to_mat(), [[10, 20], [30, 40]]);
+    /// ```
+    pub fn iter_elements_mut(&mut self) -> impl Iterator<Item = &mut T>
     {
-        &mut self.data[index]     
+        self.data.iter_mut().flat_map(|row| row.iter_mut())
     }
-}
 
-impl<T, const N: usize> Default for MatN<T, N>
-where
-    T: Default + Copy,
-{
-    fn default() -> Self 
+    /// Applies `f` to every element of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1, -2], [-3, 4]];
+    ///
+    /// assert_eq!(mat.map(i32::abs).to_mat(), [[1, 2], [3, 4]]);
+    /// ```
+    pub fn map<F>(&self, mut f: F) -> Self
+    where
+        F: FnMut(T) -> T,
     {
-        Self { data: [VecN::default(); N] }     
-    }
-}
-
-impl<T: Add<Output = T>, const N: usize> Add for MatN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
+        let mut result = *self;
 
-    fn add(self, rhs: Self) -> Self::Output
-    {
-        let mut result = self;
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        for vec in result.data.iter_mut()
         {
-            *val = *val + *other; 
+            *vec = vec.map(&mut f);
         }
-        
+
         result
-    } 
-}
-impl<T: Sub<Output = T>, const N: usize> Sub for MatN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output
+    /// Applies `f` to every element of the matrix in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut mat = mat2_raw![[1.0, -2.0], [3.0, -4.0]];
+    /// mat.apply(|val| -val);
+    ///
+    /// assert_eq!(mat.to_mat(), [[-1.0, 2.0], [-3.0, 4.0]]);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`map`](MatN::map): Returns a new matrix instead of mutating `self`.
+    pub fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
     {
-        let mut result = self;
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        for val in self.iter_elements_mut()
         {
-            *val = *val - *other; 
+            *val = f(*val);
         }
-        
-        result
-    } 
-}
-impl<T: Mul<Output = T>, const N: usize> Mul for MatN<T, N>
-where
-    T: Default + Copy + std::ops::Add<Output = T>,
-{
-    type Output = Self; 
-    
-    fn mul(self, rhs: Self) -> Self::Output 
+    }
+
+    /// Combines the elements of two matrices pairwise with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = mat2_raw![[1, 5], [3, 0]];
+    /// let b = mat2_raw![[4, 2], [3, 7]];
+    ///
+    /// assert_eq!(a.zip_with(&b, std::cmp::max).to_mat(), [[4, 5], [3, 7]]);
+    /// ```
+    pub fn zip_with<F>(&self, other: &Self, mut f: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
     {
-        let mut result = MatN::new();
-        
-        for i in 0..N {
-            for j in 0..N {
-                for k in 0..N
-                {
-                    result[i][j] = result[i][j] + self[i][k] * rhs[k][j];
-                }
-            }
+        let mut result = *self;
+
+        for (vec, other) in result.data.iter_mut().zip(other.data.iter())
+        {
+            *vec = vec.zip_with(other, &mut f);
         }
 
         result
     }
-}
 
-impl<T, const N: usize> MatVecMath<T, N> for MatN<T, N>
-where
-    T: Default + Copy
-        + std::ops::Mul<Output = T>
-        + std::ops::Add<Output = T>,
-{
-    fn mul_mat_vec(&self, vec: &VecN<T, N>) -> VecN<T, N> 
+    /// Returns an iterator over copies of the rows of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1, 2], [3, 4]];
+    /// let rows: Vec<_> = mat.row_iter().collect();
+    ///
+    /// assert_eq!(rows, vec![vec2![1, 2], vec2![3, 4]]);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`iter`](MatN::iter): Iterates over references to the rows instead.
+    pub fn row_iter(&self) -> impl Iterator<Item = VecN<T, N>> + '_
     {
-        let mut result = VecN::new();
+        self.data.iter().copied()
+    }
 
-        for (i, vector) in self.data.iter().enumerate()
+    /// Returns an iterator over the columns of the matrix, each one gathered across the rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1, 0, 5], [0, 2, 6], [0, 0, 3]];
+    /// let columns: Vec<_> = mat.col_iter().collect();
+    ///
+    /// assert_eq!(columns[0], vec3![1, 0, 0]);
+    /// assert_eq!(columns[2], vec3![5, 6, 3]);
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = VecN<T, N>> + '_
+    {
+        (0..N).map(move |col|
         {
-            for (j, val) in vector.to_arr().iter().enumerate()
+            let mut result = VecN::new();
+
+            for (val, row) in result.iter_mut().zip(self.data.iter())
             {
-                result[i] = result[i] + vec[j] * *val
+                *val = row[col];
             }
-        }
-
-        result
-    } 
-}
 
-pub type Mat2<T> = MatN<T, 2>;
-pub type Mat3<T> = MatN<T, 3>;
-pub type Mat4<T> = MatN<T, 4>;
+            result
+        })
+    }
 
-impl<T> MatTransforms<T, 2> for Mat3<T>
+    /// Creates a matrix from a flat slice of `N * N` elements, in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The elements of the matrix, row after row.
+    ///
+    /// # Returns
+    ///
+    /// The new matrix, or [`VecError::LengthMismatch`] if `data` doesn't have `N * N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let data = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let mat = Mat3::from_flat(&data).unwrap();
+    ///
+    /// assert_eq!(mat.to_mat(), [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(mat.to_flat(), data);
+    /// assert_eq!(Mat2::from_flat(&data), Err(VecError::LengthMismatch { expected: 4, found: 9 }));
+    /// ```
+    pub fn from_flat(data: &[T]) -> Result<Self, VecError>
+    {
+        if data.len() != N * N
+        {
+            return Err(VecError::LengthMismatch { expected: N * N, found: data.len() });
+        }
+
+        let mut result = Self::new();
+
+        for (val, other) in result.iter_elements_mut().zip(data.iter())
+        {
+            *val = *other;
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the elements of the matrix in a flat `Vec`, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1.0, 2.0], [3.0, 4.0]];
+    ///
+    /// assert_eq!(mat.to_flat(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`from_flat`](MatN::from_flat): The inverse of this function.
+    pub fn to_flat(&self) -> Vec<T>
+    {
+        self.iter_elements().copied().collect()
+    }
+
+    /// Computes the absolute value of every element of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1.0, -2.0], [-3.0, 4.0]];
+    ///
+    /// assert_eq!(mat.abs().to_mat(), [[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(mat.abs(), (-mat).abs());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Elements smaller than `T::default()` are negated, `NaN` is left as is.
+    pub fn abs(&self) -> Self
+    where
+        T: PartialOrd + Neg<Output = T>,
+    {
+        self.map(|val| if val < T::default() { -val } else { val })
+    }
+}
+
+impl<T, const N: usize> Identity for MatN<T, N>
+where
+    T: Default + Copy + UnitValue,
+{
+    fn identity() -> Self 
+    {
+        let mut result = MatN::new();
+         
+        for i in 0..N
+        {
+            result[i][i] = T::unit_value();
+        }
+        
+        result
+    }
+}
+
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy + UnitValue
+        + std::ops::Add<Output = T>
+        + std::ops::Mul<Output = T>,
+{
+    /// Raises the matrix to an integer power.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The exponent.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix equal to `self` multiplied by itself `n` times, or the identity when `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1, 1], [1, 0]];
+    ///
+    /// assert_eq!(mat.powi(0), Mat2::identity());
+    /// assert_eq!(mat.powi(3), mat * mat * mat);
+    /// assert_eq!(mat.powi(10).to_mat(), [[89, 55], [55, 34]]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Uses exponentiation by squaring, so only `O(log n)` matrix multiplications are made.
+    pub fn powi(&self, n: u32) -> Self
+    {
+        let mut result = Self::identity();
+        let mut base = *self;
+        let mut exp = n;
+
+        while exp > 0
+        {
+            if exp & 1 == 1
+            {
+                result = result * base;
+            }
+
+            exp >>= 1;
+            if exp > 0
+            {
+                base = base * base;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy + FromF64,
+    f64: From<T>,
+{
+    /// Rounds every element of the matrix to `decimals` decimal places.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[1.23456, 2.34567], [0.1 + 0.2, -0.005]];
+    ///
+    /// assert_eq!(mat.round_to(2).to_mat(), [[1.23, 2.35], [0.3, -0.01]]);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`VecN::round_to`].
+    pub fn round_to(&self, decimals: u32) -> Self
+    {
+        let mut result = *self;
+
+        for vec in result.data.iter_mut()
+        {
+            *vec = vec.round_to(decimals);
+        }
+
+        result
+    }
+
+    /// Linearly interpolates every element between `self` and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The matrix to interpolate to.
+    /// * `t` - The interpolation factor, `0.0` returns `self` and `1.0` returns `other`.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix equal to `self + (other - self) * t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let zero = Mat3::<f64>::new();
+    /// let identity = Mat3::<f64>::identity();
+    ///
+    /// assert_eq!(zero.lerp(&identity, 0.5), identity.mul_scalar(0.5));
+    /// assert_eq!(zero.lerp(&identity, 1.0), identity);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - This is an element-wise blend, interpolating two rotations this way doesn't give a rotation
+    ///   and the result may shear or shrink. Use [`Mat4::decompose`] and interpolate the parts for a
+    ///   rigid-body correct blend.
+    /// - `t` is not clamped, values outside of `[0, 1]` extrapolate.
+    /// - The interpolation is computed in `f64` and converted back into `T`.
+    ///
+    /// # See Also
+    ///
+    /// - [`VecN::lerp`].
+    pub fn lerp(&self, other: &Self, t: f64) -> Self
+    {
+        let mut result = *self;
+
+        for (vec, other) in result.data.iter_mut().zip(other.data.iter())
+        {
+            *vec = vec.lerp(other, t);
+        }
+
+        result
+    }
+
+    /// Adds `other` scaled by `weight` to the matrix, ie `self + other * weight`, in a single pass.
+    ///
+    /// This is meant to accumulate weighted matrices, like the bone transforms of a skinned vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The matrix to add.
+    /// * `weight` - The factor to scale `other` by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = Mat4::<f64>::from_translation(&vec3![2.0, 0.0, 0.0]);
+    /// let b = Mat4::<f64>::from_scale(&vec3![3.0, 1.0, 1.0]);
+    ///
+    /// let blended = Mat4::new().add_scaled(&a, 0.25).add_scaled(&b, 0.75);
+    ///
+    /// assert_eq!(blended, a.mul_scalar(0.25) + b.mul_scalar(0.75));
+    /// assert_eq!(blended.col_iter().last(), Some(vec4![0.5, 0.0, 0.0, 1.0]));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The computation is done in `f64` and converted back into `T`.
+    pub fn add_scaled(&self, other: &Self, weight: f64) -> Self
+    {
+        self.zip_with(other, |a, b| T::from_f64(f64::from(a) + f64::from(b) * weight))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = VecN<T, N>; 
+    
+    fn index(&self, index: usize) -> &Self::Output 
+    {
+        &self.data[index]     
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output 
+    {
+        &mut self.data[index]     
+    }
+}
+
+/// Indexes a single element of the matrix with a `(row, column)` tuple.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mut mat = Mat2::<f64>::new();
+/// mat[(1, 0)] = 5.0;
+///
+/// assert_eq!(mat[(1, 0)], 5.0);
+/// assert_eq!(mat[(1, 0)], mat[1][0]);
+/// ```
+impl<T, const N: usize> Index<(usize, usize)> for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output
+    {
+        &self.data[row].data[col]
+    }
+}
+
+impl<T, const N: usize> IndexMut<(usize, usize)> for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output
+    {
+        &mut self.data[row].data[col]
+    }
+}
+
+impl<T, const N: usize> Default for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    fn default() -> Self 
+    {
+        Self { data: [VecN::default(); N] }     
+    }
+}
+
+/// Formats the matrix row by row, like a nested array.
+///
+/// The alternate flag `{:#}` prints every row on its own line, with the columns right-aligned
+/// to their widest element. A precision, like `{:.2}`, is forwarded to every element.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mat = mat2_raw![[1.5, -2.0], [10.0, 4.0]];
+///
+/// assert_eq!(format!("{}", mat), "[[1.5, -2], [10, 4]]");
+/// assert_eq!(format!("{:#}", mat), "[[1.5, -2],\n [ 10,  4]]");
+/// assert_eq!(format!("{:#.1}", mat), "[[ 1.5, -2.0],\n [10.0,  4.0]]");
+/// ```
+impl<T, const N: usize> std::fmt::Display for MatN<T, N>
+where
+    T: Default + Copy + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let elements = self.data.map(|row| row.to_array().map(|val| match f.precision()
+        {
+            Some(precision) => format!("{:.*}", precision, val),
+            None => format!("{}", val),
+        }));
+
+        let mut widths = [0; N];
+        if f.alternate()
+        {
+            for row in elements.iter()
+            {
+                for (width, val) in widths.iter_mut().zip(row.iter())
+                {
+                    *width = (*width).max(val.chars().count());
+                }
+            }
+        }
+
+        write!(f, "[")?;
+        for (i, row) in elements.iter().enumerate()
+        {
+            if i > 0
+            {
+                write!(f, "{}", if f.alternate() { ",\n " } else { ", " })?;
+            }
+
+            write!(f, "[")?;
+            for (j, (val, width)) in row.iter().zip(widths.iter()).enumerate()
+            {
+                if j > 0
+                {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:>width$}", val, width = *width)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Add<Output = T>, const N: usize> Add for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        let mut result = self;
+        
+        for (row, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            for (val, other) in row.data.iter_mut().zip(other.data.iter())
+            {
+                *val = *val + *other;
+            }
+        }
+        
+        result
+    } 
+}
+impl<T: Sub<Output = T>, const N: usize> Sub for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        let mut result = self;
+        
+        for (row, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            for (val, other) in row.data.iter_mut().zip(other.data.iter())
+            {
+                *val = *val - *other;
+            }
+        }
+        
+        result
+    } 
+}
+/// Negates every element of the matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mat = mat2_raw![[1.0, -2.0], [-3.0, 4.0]];
+///
+/// assert_eq!((-mat).to_mat(), [[-1.0, 2.0], [3.0, -4.0]]);
+/// assert_eq!(-(-mat), mat);
+/// ```
+impl<T: Neg<Output = T>, const N: usize> Neg for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output
+    {
+        self.map(|val| -val)
+    }
+}
+/// Adds a scalar to every element of the matrix, same as [`ScalarMath::sum_scalar`].
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mat = mat2_raw![[1.0, 2.0], [3.0, 4.0]];
+///
+/// assert_eq!((mat + 1.0).to_mat(), [[2.0, 3.0], [4.0, 5.0]]);
+/// assert_eq!(mat + 1.0, mat + MatN::new_with(1.0));
+/// ```
+impl<T, const N: usize> Add<T> for MatN<T, N>
+where
+    T: Default + Copy
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output
+    {
+        self.sum_scalar(rhs)
+    }
+}
+/// Subtracts a scalar from every element of the matrix, same as [`ScalarMath::sub_scalar`].
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mat = mat2_raw![[1, 2], [3, 4]];
+///
+/// assert_eq!((mat - 1).to_mat(), [[0, 1], [2, 3]]);
+/// ```
+impl<T, const N: usize> Sub<T> for MatN<T, N>
+where
+    T: Default + Copy
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output
+    {
+        self.sub_scalar(rhs)
+    }
+}
+/// Multiplies two matrices.
+///
+/// The loops run in `i, k, j` order, so both `self` and `rhs` are read row by row instead of
+/// striding down the columns of `rhs`, and the inner loop over a row can be vectorized.
+/// Every element is still summed in increasing `k` order, so the result is the same as
+/// the textbook `i, j, k` loop. Up to 32x32 both orders run at about the same speed, the
+/// row access only pays off for larger matrices.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let a = MatN::<f64, 16>::from_fn(|i, j| (i as f64 * 0.37 - j as f64).sin());
+/// let b = MatN::<f64, 16>::from_fn(|i, j| (i as f64 + j as f64 * 1.3).cos());
+///
+/// let mut naive = MatN::<f64, 16>::new();
+/// for i in 0..16 {
+///     for j in 0..16 {
+///         for k in 0..16
+///         {
+///             naive[i][j] = naive[i][j] + a[i][k] * b[k][j];
+///         }
+///     }
+/// }
+///
+/// assert_eq!(a * b, naive);
+/// assert_eq!(mat2_raw![[1, 2], [3, 4]] * mat2_raw![[5, 6], [7, 8]], mat2_raw![[19, 22], [43, 50]]);
+/// ```
+impl<T: Mul<Output = T>, const N: usize> Mul for MatN<T, N>
+where
+    T: Default + Copy + std::ops::Add<Output = T>,
+{
+    type Output = Self; 
+    
+    fn mul(self, rhs: Self) -> Self::Output 
+    {
+        let mut result = MatN::new();
+
+        for i in 0..N {
+            for k in 0..N
+            {
+                let a = self.data[i].data[k];
+
+                for j in 0..N
+                {
+                    result.data[i].data[j] = result.data[i].data[j] + a * rhs.data[k].data[j];
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize> MatVecMath<T, N> for MatN<T, N>
+where
+    T: Default + Copy + SimdScalar
+        + std::ops::Mul<Output = T>
+        + std::ops::Add<Output = T>,
+{
+    fn mul_mat_vec(&self, vec: &VecN<T, N>) -> VecN<T, N> 
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(result) = crate::types::simd::mul_mat_vec(self, vec)
+        {
+            return result;
+        }
+
+        self.mul_mat_vec_scalar(vec)
+    } 
+}
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy
+        + std::ops::Mul<Output = T>
+        + std::ops::Add<Output = T>,
+{
+    /// Multiplies the matrix by `vec`, without the `SimdScalar` bound of [`MatVecMath`].
+    pub(crate) fn mul_mat_vec_scalar(&self, vec: &VecN<T, N>) -> VecN<T, N>
+    {
+        let mut result = VecN::new();
+
+        for (i, vector) in self.data.iter().enumerate()
+        {
+            for (j, val) in vector.to_arr().iter().enumerate()
+            {
+                result[i] = result[i] + vec[j] * *val
+            }
+        }
+
+        result
+    } 
+}
+
+pub type Mat2<T> = MatN<T, 2>;
+pub type Mat3<T> = MatN<T, 3>;
+pub type Mat4<T> = MatN<T, 4>;
+
+impl<T> Mat2<T>
+where
+    T: Default + Copy + FromF64,
+    f64: From<T>,
+{
+    /// Bilinearly interpolates the four elements of the matrix, treated as the corners of a grid cell.
+    ///
+    /// `self[0][0]` is the corner at `(0, 0)`, `self[0][1]` at `(1, 0)`, `self[1][0]` at `(0, 1)`
+    /// and `self[1][1]` at `(1, 1)`, ie `u` moves along a row and `v` moves between rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The horizontal coordinate, in `[0, 1]`.
+    /// * `v` - The vertical coordinate, in `[0, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated value at `(u, v)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let heights = mat2_raw![[0.0, 1.0], [1.0, 2.0]];
+    ///
+    /// assert_eq!(heights.bilerp(0.5, 0.5), 1.0);
+    /// assert_eq!(heights.bilerp(1.0, 0.0), 1.0);
+    /// assert_eq!(heights.bilerp(0.25, 1.0), 1.25);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `u` and `v` are not clamped, values outside of `[0, 1]` extrapolate.
+    /// - The interpolation is computed in `f64` and converted back into `T`.
+    pub fn bilerp(&self, u: f64, v: f64) -> T
+    {
+        let lerp = |a: T, b: T| f64::from(a) + (f64::from(b) - f64::from(a)) * u;
+        let top = lerp(self[0][0], self[0][1]);
+        let bottom = lerp(self[1][0], self[1][1]);
+
+        T::from_f64(top + (bottom - top) * v)
+    }
+}
+
+impl<T> MatTransforms<T, 2> for Mat3<T>
 where
     T: Default + Copy + UnitValue + SinCosTan
         + std::ops::Neg<Output = T>
@@ -443,11 +1269,11 @@ where
 {
     fn translate(&self, vec: &Vec2<T>) -> Self 
     {
-        let mut result = Mat3::identity();
-        result[0][2] = vec[0];
-        result[1][2] = vec[1];
+        let mut translation = Mat3::identity();
+        translation[0][2] = vec[0];
+        translation[1][2] = vec[1];
         
-        result
+        *self * translation
     }
     fn rotate(&self, angle: T, axis: &Vec3<T>) -> Self 
     {
@@ -484,13 +1310,68 @@ where
     }
     fn scale(&self, values: &Vec3<T>) -> Self 
     {
-        let mut result = Mat3::identity();     
+        let mut scaling = Mat3::identity();     
         
-        result[0][0] = values[0];
-        result[1][1] = values[1];
-        result[2][2] = values[2];
+        scaling[0][0] = values[0];
+        scaling[1][1] = values[1];
+        scaling[2][2] = values[2];
         
-        result
+        *self * scaling
+    }
+}
+impl<T> Mat3<T>
+where
+    T: Default + Copy + UnitValue + FromF64
+        + std::ops::Add<Output = T>
+        + std::ops::Mul<Output = T>,
+{
+    /// Creates a 2D `rotation` matrix and multiplies with `self`.
+    ///
+    /// The rotation is counter-clockwise around the origin, and is embedded in the
+    /// upper-left 2x2 block of the homogeneous 3x3 matrix:
+    ///
+    /// ```plaintext
+    /// | cos -sin 0 |
+    /// | sin  cos 0 |
+    /// | 0    0   1 |
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The rotation angle in radians.
+    ///
+    /// # Returns
+    ///
+    /// A new `Mat3` representing `self * rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat3::<f64>::identity().rotate_2d(to_radians(90.0));
+    /// let point = mat.mul_mat_vec(&vec3![1.0, 0.0, 1.0]);
+    ///
+    /// assert!((point[0] - 0.0).abs() < 1e-10);
+    /// assert!((point[1] - 1.0).abs() < 1e-10);
+    /// assert_eq!(point[2], 1.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Points are expected in homogeneous form, ie `vec3![x, y, 1]`.
+    /// - This is the correct way to rotate with a `Mat3` 2D transform, [`MatTransforms::rotate`]
+    ///   builds rotations around 3D axes instead.
+    pub fn rotate_2d(&self, angle: f64) -> Self
+    {
+        let (sin, cos) = angle.sin_cos();
+
+        let mut rotation = Mat3::identity();
+        rotation[0][0] = T::from_f64(cos);
+        rotation[0][1] = T::from_f64(-sin);
+        rotation[1][0] = T::from_f64(sin);
+        rotation[1][1] = T::from_f64(cos);
+
+        *self * rotation
     }
 }
 impl<T> MatTransforms<T, 3> for Mat4<T>
@@ -502,12 +1383,12 @@ where
 {
     fn translate(&self, vec: &Vec3<T>) -> Self 
     {
-        let mut result = Mat4::identity();             
-        result[0][3] = vec[0];
-        result[1][3] = vec[1];
-        result[2][3] = vec[2];
+        let mut translation = Mat4::identity();             
+        translation[0][3] = vec[0];
+        translation[1][3] = vec[1];
+        translation[2][3] = vec[2];
         
-        result
+        *self * translation
     }
     fn rotate(&self, angle: T, axis: &Vec3<T>) -> Self 
     {
@@ -544,12 +1425,383 @@ where
     }
     fn scale(&self, values: &Vec3<T>) -> Self 
     {
-        let mut result = Mat4::identity();     
+        let mut scaling = Mat4::identity();     
+        
+        scaling[0][0] = values[0];
+        scaling[1][1] = values[1];
+        scaling[2][2] = values[2];
         
+        *self * scaling
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Default + Copy + UnitValue,
+{
+    /// Creates a `translation` matrix, ie the identity with `vec` in the last column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::from_translation(&vec3![1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(mat.col_iter().last(), Some(vec4![1.0, 2.0, 3.0, 1.0]));
+    /// assert_eq!(mat, Mat4::identity().translate(&vec3![1.0, 2.0, 3.0]));
+    /// ```
+    pub fn from_translation(vec: &Vec3<T>) -> Self
+    {
+        let mut result = Self::identity();
+        result[0][3] = vec[0];
+        result[1][3] = vec[1];
+        result[2][3] = vec[2];
+
+        result
+    }
+
+    /// Creates a `scaling` matrix, ie a diagonal matrix with `values` and `1` in the last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::from_scale(&vec3![2, 3, 4]);
+    ///
+    /// assert_eq!(mat.mul_mat_vec(&vec4![1, 1, 1, 1]), vec4![2, 3, 4, 1]);
+    /// assert_eq!(mat, Mat4::identity().scale(&vec3![2, 3, 4]));
+    /// ```
+    pub fn from_scale(values: &Vec3<T>) -> Self
+    {
+        let mut result = Self::identity();
         result[0][0] = values[0];
         result[1][1] = values[1];
         result[2][2] = values[2];
-        
+
+        result
+    }
+
+    /// Creates a `rotation` matrix of `angle` radians around the x axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let rotated = Mat4::<f64>::from_rotation_x(to_radians(90.0)).mul_mat_vec(&vec4![0.0, 1.0, 0.0, 0.0]);
+    ///
+    /// assert!((rotated - vec4![0.0, 0.0, 1.0, 0.0]).length() < 1e-12);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The rotation is counter-clockwise when looking against the axis.
+    pub fn from_rotation_x(angle: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let (sin, cos) = angle.sin_cos();
+        let mut result = Self::identity();
+        result[1][1] = T::from_f64(cos);
+        result[1][2] = T::from_f64(-sin);
+        result[2][1] = T::from_f64(sin);
+        result[2][2] = T::from_f64(cos);
+
+        result
+    }
+
+    /// Creates a `rotation` matrix of `angle` radians around the y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let rotated = Mat4::<f64>::from_rotation_y(to_radians(90.0)).mul_mat_vec(&vec4![0.0, 0.0, 1.0, 0.0]);
+    ///
+    /// assert!((rotated - vec4![1.0, 0.0, 0.0, 0.0]).length() < 1e-12);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The rotation is counter-clockwise when looking against the axis.
+    pub fn from_rotation_y(angle: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let (sin, cos) = angle.sin_cos();
+        let mut result = Self::identity();
+        result[0][0] = T::from_f64(cos);
+        result[0][2] = T::from_f64(sin);
+        result[2][0] = T::from_f64(-sin);
+        result[2][2] = T::from_f64(cos);
+
+        result
+    }
+
+    /// Creates a `rotation` matrix of `angle` radians around the z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let rotated = Mat4::<f64>::from_rotation_z(to_radians(90.0)).mul_mat_vec(&vec4![1.0, 0.0, 0.0, 0.0]);
+    ///
+    /// assert!((rotated - vec4![0.0, 1.0, 0.0, 0.0]).length() < 1e-12);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The rotation is counter-clockwise when looking against the axis.
+    pub fn from_rotation_z(angle: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let (sin, cos) = angle.sin_cos();
+        let mut result = Self::identity();
+        result[0][0] = T::from_f64(cos);
+        result[0][1] = T::from_f64(-sin);
+        result[1][0] = T::from_f64(sin);
+        result[1][1] = T::from_f64(cos);
+
+        result
+    }
+
+    /// Transforms a 3D point by the matrix, ie as `[x, y, z, 1]` so it is affected by the translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::from_translation(&vec3![10.0, 0.0, 0.0]).scale(&vec3![2.0, 2.0, 2.0]);
+    ///
+    /// assert_eq!(mat.transform_point3(&vec3![1.0, 2.0, 3.0]), vec3![12.0, 4.0, 6.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The matrix is assumed to be affine, the resulting `w` is dropped without a perspective
+    ///   divide. Use [`project_point`](Mat4::project_point) for projection matrices.
+    pub fn transform_point3(&self, point: &Vec3<T>) -> Vec3<T>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.mul_mat_vec_scalar(&point.to_homogeneous_point()).resize()
+    }
+
+    /// Transforms a 3D direction by the matrix, ie as `[x, y, z, 0]` so it ignores the translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::from_translation(&vec3![10.0, 0.0, 0.0]).scale(&vec3![2.0, 2.0, 2.0]);
+    ///
+    /// assert_eq!(mat.transform_vector3(&vec3![1.0, 2.0, 3.0]), vec3![2.0, 4.0, 6.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The resulting `w` is dropped without a perspective divide.
+    pub fn transform_vector3(&self, vector: &Vec3<T>) -> Vec3<T>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.mul_mat_vec_scalar(&vector.to_homogeneous_direction()).resize()
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Default + Copy + UnitValue + FromF64
+        + std::ops::Add<Output = T>
+        + std::ops::Mul<Output = T>,
+    f64: From<T>,
+{
+    /// Creates a `rotation` matrix of `angle` around an arbitrary `axis` and multiplies with `self`.
+    ///
+    /// The rotation matrix is built with the Rodrigues rotation formula, so it is a true
+    /// axis-angle rotation, unlike [`MatTransforms::rotate`] which chains rotations around each axis:
+    ///
+    /// ```plaintext
+    /// R = cos * I + sin * [axis]x + (1 - cos) * (axis * axis^T)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The unit vector to rotate around.
+    /// * `angle` - The rotation angle in radians, counter-clockwise when looking against `axis`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Mat4` representing `self * rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::<f64>::identity().rotate_axis_angle(&vec3![0.0, 0.0, 1.0], to_radians(90.0));
+    /// let rotated = mat.mul_mat_vec(&vec4![1.0, 0.0, 0.0, 0.0]);
+    ///
+    /// for (a, b) in rotated.iter().zip([0.0, 1.0, 0.0, 0.0].iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `axis` is expected to be normalized, it is not normalized again.
+    /// - The rotation is computed in `f64` and converted back into `T`.
+    pub fn rotate_axis_angle(&self, axis: &Vec3<T>, angle: f64) -> Self
+    {
+        *self * Self::from_axis_angle(axis, angle)
+    }
+
+    /// Creates a `rotation` matrix of `angle` around an arbitrary `axis`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The unit vector to rotate around.
+    /// * `angle` - The rotation angle in radians, counter-clockwise when looking against `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let axis = vec3![0.0, 0.6, 0.8];
+    ///
+    /// assert_eq!(Mat4::<f64>::from_axis_angle(&axis, 0.7), Mat4::identity().rotate_axis_angle(&axis, 0.7));
+    /// assert_eq!(Mat4::<f64>::from_axis_angle(&vec3![1.0, 0.0, 0.0], 0.7), Mat4::from_rotation_x(0.7));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `axis` is expected to be normalized, it is not normalized again.
+    /// - The rotation is computed in `f64` and converted back into `T`.
+    ///
+    /// # See Also
+    ///
+    /// - [`rotate_axis_angle`](Mat4::rotate_axis_angle): Composes the rotation with an existing matrix.
+    pub fn from_axis_angle(axis: &Vec3<T>, angle: f64) -> Self
+    {
+        let (x, y, z) = (f64::from(axis[0]), f64::from(axis[1]), f64::from(axis[2]));
+        let (sin, cos) = angle.sin_cos();
+        let t = 1.0 - cos;
+
+        let rotation = [
+            [cos + x*x*t, x*y*t - z*sin, x*z*t + y*sin],
+            [y*x*t + z*sin, cos + y*y*t, y*z*t - x*sin],
+            [z*x*t - y*sin, z*y*t + x*sin, cos + z*z*t],
+        ];
+
+        let mut result = Mat4::identity();
+        for (row, other) in result.iter_mut().zip(rotation.iter())
+        {
+            for (val, other) in row.iter_mut().zip(other.iter())
+            {
+                *val = T::from_f64(*other);
+            }
+        }
+
         result
     }
+}
+
+impl<T> Mat4<T>
+where
+    T: Default + Copy + FromF64,
+    f64: From<T>,
+{
+    /// Decomposes a transform matrix into its translation, rotation and scale.
+    ///
+    /// This is the inverse of building a transform with `translate`, `rotate` and `scale`,
+    /// ie `self = T * R * S`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the translation, the rotation as a `Mat3` and the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let axis = vec3![0.0_f64, 0.6, 0.8];
+    /// let mat = Mat4::<f64>::identity()
+    ///     .translate(&vec3![1.0, -2.0, 3.0])
+    ///     .rotate(0.9, &axis)
+    ///     .scale(&vec3![2.0, 0.5, 4.0]);
+    ///
+    /// let (translation, rotation, scale) = mat.decompose();
+    /// let expected = Mat3::<f64>::identity().rotate(0.9, &axis);
+    ///
+    /// assert_eq!(translation, vec3![1.0, -2.0, 3.0]);
+    /// for (a, b) in scale.iter().zip([2.0, 0.5, 4.0].iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    /// for (a, b) in rotation.iter_elements().zip(expected.iter_elements())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    ///
+    /// let mirror = Mat4::<f64>::identity().scale(&vec3![-1.0, 1.0, 1.0]);
+    /// let (_, rotation, scale) = mirror.decompose();
+    ///
+    /// assert_eq!(scale, vec3![-1.0, 1.0, 1.0]);
+    /// assert_eq!(rotation, Mat3::identity());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The matrix is assumed to have no skew or perspective, ie the last row is `[0, 0, 0, 1]`.
+    /// - A negative scale is only detected as a whole, when the determinant of the upper 3x3 is
+    ///   negative the `x` scale is negated.
+    /// - When a scale is zero the matching column of the rotation is left as zero.
+    /// - There is no quaternion type yet, so the rotation is returned as a `Mat3`.
+    /// - The decomposition is computed in `f64` and converted back into `T`.
+    pub fn decompose(&self) -> (Vec3<T>, Mat3<T>, Vec3<T>)
+    {
+        let mut columns = [[0.0; 3]; 3];
+
+        for (j, column) in columns.iter_mut().enumerate()
+        {
+            for (i, val) in column.iter_mut().enumerate()
+            {
+                *val = f64::from(self[i][j]);
+            }
+        }
+
+        let mut scale = [0.0; 3];
+        for (val, column) in scale.iter_mut().zip(columns.iter())
+        {
+            *val = column.iter().map(|x| x * x).sum::<f64>().sqrt();
+        }
+
+        let [a, b, c] = columns;
+        let determinant = a[0] * (b[1] * c[2] - b[2] * c[1])
+            - b[0] * (a[1] * c[2] - a[2] * c[1])
+            + c[0] * (a[1] * b[2] - a[2] * b[1]);
+        if determinant < 0.0
+        {
+            scale[0] = -scale[0];
+        }
+
+        let mut rotation = Mat3::new();
+        for (j, (column, scale)) in columns.iter().zip(scale.iter()).enumerate()
+        {
+            if *scale == 0.0
+            {
+                continue;
+            }
+
+            for (i, val) in column.iter().enumerate()
+            {
+                rotation[i][j] = T::from_f64(val / scale);
+            }
+        }
+
+        let translation = VecN::from_array(&[self[0][3], self[1][3], self[2][3]]);
+        let scale = VecN::from_array(&[T::from_f64(scale[0]), T::from_f64(scale[1]), T::from_f64(scale[2])]);
+
+        (translation, rotation, scale)
+    }
 }
\ No newline at end of file
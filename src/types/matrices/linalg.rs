@@ -0,0 +1,1341 @@
+use std::ops::{Add, Sub, Mul, Div};
+use super::{MatN, Mat2, Mat3, Mat4};
+use crate::types::{math::{Epsilon, ExactDiv, FromF64, UnitValue}, vectors::{VecN, Vec3}};
+
+/// Returns the largest absolute value of a pivot of `mat` that is treated as zero.
+///
+/// The tolerance is relative to the largest absolute element, so scaling the whole matrix up or
+/// down does not change which matrices are considered singular.
+fn pivot_tolerance<const N: usize>(mat: &[[f64; N]; N]) -> f64
+{
+    let max_norm = mat.iter()
+        .flatten()
+        .fold(0.0, |max, val| f64::max(max, val.abs()));
+
+    f64::EPSILON * max_norm * N as f64
+}
+
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy,
+    f64: From<T>,
+{
+    /// Returns a copy of the matrix with every element converted into `f64`.
+    fn to_mat_f64(self) -> [[f64; N]; N]
+    {
+        let mut result = [[0.0; N]; N];
+
+        for (row, other) in result.iter_mut().zip(self.iter())
+        {
+            for (val, other) in row.iter_mut().zip(other.iter())
+            {
+                *val = f64::from(*other);
+            }
+        }
+
+        result
+    }
+
+    /// Creates a matrix from a `f64` 2D array, converting every element into `T`.
+    fn from_mat_f64(data: &[[f64; N]; N]) -> Self
+    where
+        T: FromF64,
+    {
+        let mut result = Self::new();
+
+        for (row, other) in result.iter_mut().zip(data.iter())
+        {
+            for (val, other) in row.iter_mut().zip(other.iter())
+            {
+                *val = T::from_f64(*other);
+            }
+        }
+
+        result
+    }
+
+    /// Checks if the matrix is orthogonal, ie `self * self.transpose()` is the identity.
+    ///
+    /// Orthogonal matrices represent pure rotations and reflections, this can be used to detect
+    /// the numerical drift accumulated by long chains of transforms.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The biggest absolute difference allowed between each element and the identity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the matrix is orthogonal within `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let rotation = Mat3::<f64>::identity().rotate_2d(0.3).rotate_2d(1.2);
+    /// let scaling = Mat3::<f64>::identity().scale(&vec3![2.0, 1.0, 1.0]);
+    ///
+    /// assert!(rotation.is_orthogonal(1e-10));
+    /// assert!(!scaling.is_orthogonal(1e-10));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The product is computed in `f64`.
+    pub fn is_orthogonal(&self, epsilon: f64) -> bool
+    {
+        let mat = self.to_mat_f64();
+
+        for (i, row) in mat.iter().enumerate() {
+            for (j, other) in mat.iter().enumerate()
+            {
+                let dot = row.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>();
+                let expected = if i == j { 1.0 } else { 0.0 };
+
+                if (dot - expected).abs() > epsilon
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Checks if the matrix is the identity, within a tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The biggest absolute difference allowed between each element and the identity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every element is within `epsilon` of the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+    ///
+    /// assert!(Mat4::<f64>::identity().is_identity(0.0));
+    /// assert!((mat * mat.inverse().unwrap()).is_identity(1e-10));
+    /// assert!(!Mat3::<f64>::identity().scale(&vec3![2.0, 1.0, 1.0]).is_identity(1e-10));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The elements are compared in `f64`.
+    pub fn is_identity(&self, epsilon: f64) -> bool
+    {
+        self.to_mat_f64().iter().enumerate().all(|(i, row)|
+        {
+            row.iter().enumerate().all(|(j, val)|
+            {
+                let expected = if i == j { 1.0 } else { 0.0 };
+
+                (val - expected).abs() <= epsilon
+            })
+        })
+    }
+
+    /// Solves the linear system `self * x = b` for `x`.
+    ///
+    /// Uses Gauss-Jordan elimination with partial pivoting, which is more numerically stable
+    /// than multiplying `b` by the inverse of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right hand side of the system.
+    ///
+    /// # Returns
+    ///
+    /// The solution `x`, or `None` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+    /// let mat = mat3_raw![[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+    /// let x = mat.solve(&vec3![8.0, -11.0, -3.0]).unwrap();
+    ///
+    /// for (a, b) in x.iter().zip([2.0_f64, 3.0, -1.0].iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    ///
+    /// let singular = mat2_raw![[1.0, 2.0], [2.0, 4.0]];
+    /// assert_eq!(singular.solve(&vec2![1.0, 1.0]), None);
+    ///
+    /// // Small elements alone do not make the matrix singular.
+    /// let tiny = Mat3::<f64>::identity().mul_scalar(1e-13);
+    /// let x = tiny.solve(&vec3![1e-13, 2e-13, -3e-13]).unwrap();
+    ///
+    /// for (a, b) in x.iter().zip([1.0_f64, 2.0, -3.0].iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The elimination is computed in `f64` and the result converted back into `T`.
+    /// - A pivot with absolute value up to `N * f64::EPSILON` times the largest absolute element
+    ///   of the matrix is treated as zero.
+    pub fn solve(&self, b: &VecN<T, N>) -> Option<VecN<T, N>>
+    where
+        T: FromF64,
+    {
+        let mut mat = self.to_mat_f64();
+        let tolerance = pivot_tolerance(&mat);
+        let mut rhs = [0.0; N];
+
+        for (val, other) in rhs.iter_mut().zip(b.iter())
+        {
+            *val = f64::from(*other);
+        }
+
+        for col in 0..N
+        {
+            let pivot = (col..N)
+                .max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs()))?;
+
+            if mat[pivot][col].abs() <= tolerance
+            {
+                return None;
+            }
+
+            mat.swap(col, pivot);
+            rhs.swap(col, pivot);
+
+            let scale = mat[col][col];
+            for val in mat[col].iter_mut()
+            {
+                *val /= scale;
+            }
+            rhs[col] /= scale;
+
+            for row in 0..N
+            {
+                if row == col
+                {
+                    continue;
+                }
+
+                let factor = mat[row][col];
+                let pivot_row = mat[col];
+                for (val, other) in mat[row].iter_mut().zip(pivot_row.iter()).skip(col)
+                {
+                    *val -= factor * other;
+                }
+                rhs[row] -= factor * rhs[col];
+            }
+        }
+
+        let mut result = VecN::new();
+        for (val, other) in result.iter_mut().zip(rhs.iter())
+        {
+            *val = T::from_f64(*other);
+        }
+
+        Some(result)
+    }
+
+    /// Computes the LU decomposition of the matrix, with partial pivoting.
+    ///
+    /// Finds a lower triangular matrix `L` with ones on the diagonal, an upper triangular matrix `U`
+    /// and a row permutation `P` such that `P * self = L * U`. Once computed, the decomposition can
+    /// be reused to solve many systems with the same matrix.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with `L` and `U` as `f64` matrices and the permutation, where `permutation[i]` is the
+    /// row of `self` that ended up in row `i`. Returns `None` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1.0_f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+    /// let (l, u, permutation) = mat.lu().unwrap();
+    ///
+    /// let mut permuted = Mat3::new();
+    /// for (i, row) in permutation.iter().enumerate()
+    /// {
+    ///     permuted[i] = mat[*row];
+    /// }
+    ///
+    /// let product = l * u;
+    /// for i in 0..3 {
+    ///     for j in 0..3
+    ///     {
+    ///         assert!((product[i][j] - permuted[i][j]).abs() < 1e-10);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(mat2_raw![[1.0, 2.0], [2.0, 4.0]].lu(), None);
+    ///
+    /// // The factors of an integer matrix are not integers.
+    /// let (l, u, permutation) = mat3_raw![[2, 1, 1], [1, 3, 2], [1, 0, 0]].lu().unwrap();
+    /// assert_eq!(permutation, [0, 1, 2]);
+    /// assert_eq!(l.to_mat(), [[1.0, 0.0, 0.0], [0.5, 1.0, 0.0], [0.5, -0.2, 1.0]]);
+    /// assert_eq!((u[0][0], u[1][1]), (2.0, 2.5));
+    /// assert!((u[2][2] + 0.2).abs() < 1e-12);
+    ///
+    /// // Small elements alone do not make the matrix singular.
+    /// let (l, u, _) = Mat2::<f64>::identity().mul_scalar(1e-13).lu().unwrap();
+    /// assert_eq!(l, Mat2::identity());
+    /// assert_eq!(u, Mat2::identity().mul_scalar(1e-13));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The factors are returned as `MatN<f64, N>`, not `MatN<T, N>`. The decomposition is
+    ///   computed in `f64`, and the factors of an integer matrix are generally not integers, so
+    ///   converting them back into `T` would truncate them and break `P * self = L * U`.
+    /// - A pivot with absolute value up to `N * f64::EPSILON` times the largest absolute element
+    ///   of the matrix is treated as zero.
+    pub fn lu(&self) -> Option<(MatN<f64, N>, MatN<f64, N>, [usize; N])>
+    {
+        let mut upper = self.to_mat_f64();
+        let tolerance = pivot_tolerance(&upper);
+        let mut lower = [[0.0; N]; N];
+        let mut permutation = [0; N];
+
+        for (i, val) in permutation.iter_mut().enumerate()
+        {
+            *val = i;
+        }
+
+        for col in 0..N
+        {
+            let pivot = (col..N)
+                .max_by(|&a, &b| upper[a][col].abs().total_cmp(&upper[b][col].abs()))?;
+
+            if upper[pivot][col].abs() <= tolerance
+            {
+                return None;
+            }
+
+            upper.swap(col, pivot);
+            lower.swap(col, pivot);
+            permutation.swap(col, pivot);
+
+            for row in (col + 1)..N
+            {
+                let factor = upper[row][col] / upper[col][col];
+                let pivot_row = upper[col];
+
+                lower[row][col] = factor;
+                for (val, other) in upper[row].iter_mut().zip(pivot_row.iter()).skip(col)
+                {
+                    *val -= factor * other;
+                }
+            }
+        }
+
+        for (i, row) in lower.iter_mut().enumerate()
+        {
+            row[i] = 1.0;
+        }
+
+        Some((MatN::from_mat(&lower), MatN::from_mat(&upper), permutation))
+    }
+
+    /// Computes the determinant of the matrix, for any size `N`.
+    ///
+    /// Reduces the matrix to upper triangular form with Gaussian elimination and partial
+    /// pivoting, the determinant is the product of the pivots.
+    ///
+    /// # Returns
+    ///
+    /// The determinant as `f64`, `0.0` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+    ///
+    /// assert!((mat.determinant_lu() - 1.0).abs() < 1e-10);
+    /// assert_eq!(mat2_raw![[1, 2], [2, 4]].determinant_lu(), 0.0);
+    ///
+    /// // Small elements alone do not make the matrix singular.
+    /// let tiny = Mat2::<f64>::identity().mul_scalar(1e-13);
+    /// assert_eq!(tiny.determinant_lu(), 1e-13 * 1e-13);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `Mat2`, `Mat3` and `Mat4` have a closed-form [`determinant`](Mat4::determinant),
+    ///   which is faster and computed in `T`.
+    /// - A pivot with absolute value up to `N * f64::EPSILON` times the largest absolute element
+    ///   of the matrix is treated as zero.
+    pub fn determinant_lu(&self) -> f64
+    {
+        let mut mat = self.to_mat_f64();
+        let tolerance = pivot_tolerance(&mat);
+        let mut result = 1.0;
+
+        for col in 0..N
+        {
+            let pivot = (col..N)
+                .max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs()))
+                .unwrap_or(col);
+
+            if mat[pivot][col].abs() <= tolerance
+            {
+                return 0.0;
+            }
+
+            if pivot != col
+            {
+                mat.swap(col, pivot);
+                result = -result;
+            }
+            result *= mat[col][col];
+
+            for row in (col + 1)..N
+            {
+                let factor = mat[row][col] / mat[col][col];
+                let pivot_row = mat[col];
+
+                for (val, other) in mat[row].iter_mut().zip(pivot_row.iter()).skip(col)
+                {
+                    *val -= factor * other;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Computes the numerical rank of the matrix, ie the number of linearly independent rows.
+    ///
+    /// Reduces the matrix to row echelon form with Gaussian elimination and partial pivoting,
+    /// the rank is the number of pivots bigger than `epsilon`.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - Pivots with an absolute value smaller or equal to this are treated as zero.
+    ///
+    /// # Returns
+    ///
+    /// The rank of the matrix, `N` if the matrix is invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let full = mat3_raw![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+    /// let repeated = mat3_raw![[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [5.0, 6.0, 0.0]];
+    ///
+    /// assert_eq!(full.rank(1e-10), 3);
+    /// assert_eq!(repeated.rank(1e-10), 2);
+    /// assert_eq!(mat2_raw![[1, 2], [2, 4]].rank(1e-10), 1);
+    /// assert_eq!(Mat4::<f64>::new().rank(1e-10), 0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The elimination is computed in `f64`.
+    pub fn rank(&self, epsilon: f64) -> usize
+    {
+        let mut mat = self.to_mat_f64();
+        let mut rank = 0;
+
+        for col in 0..N
+        {
+            if rank == N
+            {
+                break;
+            }
+
+            let pivot = (rank..N)
+                .max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs()))
+                .unwrap_or(rank);
+
+            if mat[pivot][col].abs() <= epsilon
+            {
+                continue;
+            }
+
+            mat.swap(rank, pivot);
+
+            for row in (rank + 1)..N
+            {
+                let factor = mat[row][col] / mat[rank][col];
+                let pivot_row = mat[rank];
+
+                for (val, other) in mat[row].iter_mut().zip(pivot_row.iter()).skip(col)
+                {
+                    *val -= factor * other;
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// Computes the inverse of the matrix, for any size `N`.
+    ///
+    /// Uses Gauss-Jordan elimination with partial pivoting on the matrix augmented with the identity.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrix, or `None` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[4.0_f64, 7.0], [2.0, 6.0]];
+    /// let inverse = mat.inverse_gauss_jordan().unwrap();
+    /// let product = mat * inverse;
+    ///
+    /// for i in 0..2 {
+    ///     for j in 0..2
+    ///     {
+    ///         let expected = if i == j { 1.0 } else { 0.0 };
+    ///         assert!((product[i][j] - expected).abs() < 1e-10);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(mat2_raw![[1.0, 2.0], [2.0, 4.0]].inverse_gauss_jordan(), None);
+    ///
+    /// // Small elements alone do not make the matrix singular, for any size.
+    /// let tiny = MatN::<f64, 5>::identity().mul_scalar(1e-7);
+    /// assert!((tiny * tiny.inverse_gauss_jordan().unwrap()).is_identity(1e-10));
+    /// assert!(Mat4::<f64>::identity().mul_scalar(1e-7).inverse().is_some());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `Mat2`, `Mat3` and `Mat4` have a closed-form [`inverse`](Mat4::inverse), which is faster.
+    /// - The elimination is computed in `f64` and the result converted back into `T`.
+    /// - A pivot with absolute value up to `N * f64::EPSILON` times the largest absolute element
+    ///   of the matrix is treated as zero. The closed-form `inverse` applies the same tolerance to
+    ///   the determinant, scaled by the largest absolute element to the power of `N - 1`.
+    pub fn inverse_gauss_jordan(&self) -> Option<Self>
+    where
+        T: FromF64,
+    {
+        Some(Self::from_mat_f64(&self.inverse_f64()?))
+    }
+
+    /// Computes the inverse of the matrix together with its condition number.
+    ///
+    /// The condition number `||self|| * ||self^-1||` (with the maximum absolute row sum norm)
+    /// tells how much the relative error of the input can grow in the result. It is `1` for
+    /// orthogonal matrices and grows without bound as the matrix gets closer to singular, so a
+    /// huge value warns that the inverse is unreliable even though it exists.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the inverse and the condition number, or `None` if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let (inverse, condition) = Mat3::<f64>::identity().inverse_with_condition().unwrap();
+    /// assert_eq!(inverse, Mat3::identity());
+    /// assert_eq!(condition, 1.0);
+    ///
+    /// let (_, condition) = mat2_raw![[4.0, 7.0], [2.0, 6.0]].inverse_with_condition().unwrap();
+    /// // `||A|| = 11` and `A^-1 = [[0.6, -0.7], [-0.2, 0.4]]`, so `||A^-1|| = 1.3`.
+    /// assert!((condition - 14.3).abs() < 1e-10);
+    ///
+    /// // Almost singular, the rows are nearly parallel.
+    /// let (_, condition) = mat2_raw![[1.0, 1.0], [1.0, 1.0 + 1e-10]].inverse_with_condition().unwrap();
+    /// assert!(condition > 1e10);
+    ///
+    /// assert_eq!(mat2_raw![[1.0, 2.0], [2.0, 4.0]].inverse_with_condition(), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The inverse is computed like [`inverse_gauss_jordan`](MatN::inverse_gauss_jordan), and the
+    ///   condition number in `f64` from the unrounded inverse.
+    /// - A pivot with absolute value up to `N * f64::EPSILON` times the largest absolute element
+    ///   of the matrix is treated as zero.
+    pub fn inverse_with_condition(&self) -> Option<(Self, f64)>
+    where
+        T: FromF64,
+    {
+        let inverse = self.inverse_f64()?;
+        let norm = |mat: &[[f64; N]; N]| mat.iter()
+            .map(|row| row.iter().map(|val| val.abs()).sum::<f64>())
+            .fold(0.0, f64::max);
+
+        Some((Self::from_mat_f64(&inverse), norm(&self.to_mat_f64()) * norm(&inverse)))
+    }
+
+    /// Computes the inverse of the matrix in `f64` with Gauss-Jordan elimination.
+    fn inverse_f64(&self) -> Option<[[f64; N]; N]>
+    {
+        let mut mat = self.to_mat_f64();
+        let tolerance = pivot_tolerance(&mat);
+        let mut inverse = [[0.0; N]; N];
+
+        for (i, row) in inverse.iter_mut().enumerate()
+        {
+            row[i] = 1.0;
+        }
+
+        for col in 0..N
+        {
+            let pivot = (col..N)
+                .max_by(|&a, &b| mat[a][col].abs().total_cmp(&mat[b][col].abs()))?;
+
+            if mat[pivot][col].abs() <= tolerance
+            {
+                return None;
+            }
+
+            mat.swap(col, pivot);
+            inverse.swap(col, pivot);
+
+            let scale = mat[col][col];
+            for (val, other) in mat[col].iter_mut().zip(inverse[col].iter_mut())
+            {
+                *val /= scale;
+                *other /= scale;
+            }
+
+            for row in 0..N
+            {
+                if row == col
+                {
+                    continue;
+                }
+
+                let factor = mat[row][col];
+                let pivot_row = mat[col];
+                let pivot_inverse = inverse[col];
+
+                for (val, other) in mat[row].iter_mut().zip(pivot_row.iter()).skip(col)
+                {
+                    *val -= factor * other;
+                }
+                for (val, other) in inverse[row].iter_mut().zip(pivot_inverse.iter())
+                {
+                    *val -= factor * other;
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    /// Computes the Frobenius norm of the matrix, ie the square root of the sum of the squares
+    /// of every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(Mat3::<f64>::identity().frobenius_norm(), 3.0_f64.sqrt());
+    /// assert_eq!(mat2_raw![[1, -2], [-2, 4]].frobenius_norm(), 5.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The norm is computed in `f64`.
+    pub fn frobenius_norm(&self) -> f64
+    {
+        self.iter_elements()
+            .map(|val| f64::from(*val).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Computes the max norm of the matrix, ie the largest absolute value of its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(mat2_raw![[1, -7], [3, 4]].max_norm(), 7.0);
+    /// assert_eq!(Mat3::<f64>::new().max_norm(), 0.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The norm is computed in `f64`.
+    pub fn max_norm(&self) -> f64
+    {
+        self.iter_elements()
+            .map(|val| f64::from(*val).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Orthonormalizes the rows of the matrix with the modified Gram-Schmidt process.
+    ///
+    /// Each row has its projections onto the previous (already orthonormal) rows removed, and is
+    /// then normalized. This can be used to correct the drift of a rotation matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new matrix whose rows are orthonormal and span the same space as the original rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[2.0_f64, 1.0, 0.0], [1.0, 3.0, 1.0], [0.5, 0.0, 4.0]];
+    /// let orthonormal = mat.orthonormalize();
+    ///
+    /// let product = orthonormal * orthonormal.transpose();
+    ///
+    /// for i in 0..3 {
+    ///     for j in 0..3
+    ///     {
+    ///         let expected = if i == j { 1.0 } else { 0.0 };
+    ///         assert!((product[(i, j)] - expected).abs() < 1e-10);
+    ///     }
+    /// }
+    /// assert!(orthonormal.is_orthogonal(1e-10));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Rows that are linearly dependent on the previous ones become zero vectors.
+    /// - The projections and normalization are computed in `f64` and converted back into `T`.
+    pub fn orthonormalize(&self) -> Self
+    where
+        T: FromF64 + Sub<Output = T>,
+    {
+        let mut result = *self;
+
+        for i in 0..N
+        {
+            let mut row = result[i];
+
+            for basis in result.iter().take(i)
+            {
+                row = row.zip_with(&row.project_onto(basis), |a, b| a - b);
+            }
+
+            result[i] = row.try_normalize().unwrap_or_default();
+        }
+
+        result
+    }
+}
+
+/// Divides the adjugate of `mat` by its determinant, ie computes the inverse from [`Mat4::inverse_scaled`].
+///
+/// Like the pivots of [`inverse_gauss_jordan`](MatN::inverse_gauss_jordan), the determinant is
+/// treated as zero up to `N * epsilon * max_norm^N`, where `max_norm` is the largest absolute element
+/// of `mat`, so a rounding residue is not inverted. Integers have no tolerance, and their inverse only
+/// exists when the determinant divides every element of the adjugate.
+fn divide_adjugate<T, const N: usize>(mat: &MatN<T, N>, adjugate: &MatN<T, N>, determinant: T) -> Option<MatN<T, N>>
+where
+    T: Default + Copy + PartialOrd + ExactDiv + Epsilon
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>,
+{
+    let zero = T::default();
+    let abs = |val: T| if val < zero { zero - val } else { val };
+    let max_norm = mat.iter_elements()
+        .fold(zero, |max, &val| if abs(val) > max { abs(val) } else { max });
+
+    // Starting from `epsilon` keeps the integer tolerance at zero instead of overflowing.
+    let scaled = (0..N).fold(T::epsilon(), |acc, _| acc * max_norm);
+    let tolerance = (0..N).fold(zero, |acc, _| acc + scaled);
+
+    if abs(determinant) <= tolerance
+    {
+        return None;
+    }
+
+    let mut inverse = *adjugate;
+
+    for val in inverse.iter_elements_mut()
+    {
+        *val = val.exact_div(determinant)?;
+    }
+
+    Some(inverse)
+}
+
+impl<T> Mat2<T>
+where
+    T: Default + Copy + PartialEq
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// Returns the adjugate and the determinant of the matrix.
+    fn adjugate_determinant(&self) -> (Self, T)
+    {
+        let m = self;
+        let zero = T::default();
+        let adjugate = MatN::from_mat(&[
+            [m[1][1], zero - m[0][1]],
+            [zero - m[1][0], m[0][0]],
+        ]);
+
+        (adjugate, m[0][0] * m[1][1] - m[0][1] * m[1][0])
+    }
+
+    /// Computes the determinant of the matrix in closed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[4, 7], [2, 6]];
+    ///
+    /// assert_eq!(mat.determinant(), 10);
+    /// assert_eq!(mat.determinant() as f64, mat.determinant_lu().round());
+    /// ```
+    pub fn determinant(&self) -> T
+    {
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
+    }
+
+    /// Computes the inverse of the matrix in closed form, ie the adjugate divided by the determinant.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrix, or `None` if the determinant is zero (up to rounding) or, for
+    /// integer matrices, if the inverse has non-integer elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[4.0_f64, 7.0], [2.0, 6.0]];
+    /// let inverse = mat.inverse().unwrap();
+    /// let generic = mat.inverse_gauss_jordan().unwrap();
+    ///
+    /// for (a, b) in inverse.iter_elements().zip([0.6, -0.7, -0.2, 0.4].iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    /// for (a, b) in inverse.iter_elements().zip(generic.iter_elements())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    ///
+    /// assert_eq!(mat2_raw![[1.0, 2.0], [2.0, 4.0]].inverse(), None);
+    ///
+    /// // The determinant is 1, so the inverse has integer elements.
+    /// assert_eq!(mat2_raw![[2, 1], [1, 1]].inverse().unwrap().to_mat(), [[1, -1], [-1, 2]]);
+    /// // The inverse is `[[0.6, -0.7], [-0.2, 0.4]]`, which has no integer representation.
+    /// assert_eq!(mat2_raw![[4, 7], [2, 6]].inverse(), None);
+    ///
+    /// // The determinant is not zero, but it is tiny next to the elements, so both paths reject it.
+    /// let nearly_singular = mat2_raw![[1.0, 0.0], [0.0, 1e-300]];
+    /// assert_eq!(nearly_singular.inverse(), None);
+    /// assert_eq!(nearly_singular.inverse_gauss_jordan(), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - A determinant with absolute value up to `N * epsilon * max_norm^N` is treated as zero, where
+    ///   `max_norm` is the largest absolute element. This matches the pivot tolerance of
+    ///   [`inverse_gauss_jordan`](MatN::inverse_gauss_jordan), so both paths reject the same
+    ///   rounding residues, and integer matrices are only singular with a determinant of zero.
+    /// - An integer matrix only has an integer inverse when its determinant divides every element
+    ///   of the adjugate, [`inverse_scaled`](Mat2::inverse_scaled) keeps the exact inverse otherwise.
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: PartialOrd + ExactDiv + Epsilon,
+    {
+        let (adjugate, determinant) = self.inverse_scaled()?;
+
+        divide_adjugate(self, &adjugate, determinant)
+    }
+
+    /// Computes the inverse of the matrix as an adjugate and a determinant, without dividing them.
+    ///
+    /// The inverse is `adjugate / determinant`, keeping both parts separate allows integer
+    /// matrices to stay exact until the division is really needed.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the adjugate and the determinant, or `None` if the determinant is exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat2_raw![[4, 7], [2, 6]];
+    /// let (adjugate, determinant) = mat.inverse_scaled().unwrap();
+    ///
+    /// assert_eq!(adjugate.to_mat(), [[6, -7], [-2, 4]]);
+    /// assert_eq!(determinant, 10);
+    /// assert_eq!(mat * adjugate, Mat2::identity().mul_scalar(determinant));
+    /// assert_eq!(mat2_raw![[1, 2], [2, 4]].inverse_scaled(), None);
+    /// ```
+    pub fn inverse_scaled(&self) -> Option<(Self, T)>
+    {
+        let (adjugate, determinant) = self.adjugate_determinant();
+
+        if determinant == T::default()
+        {
+            return None;
+        }
+
+        Some((adjugate, determinant))
+    }
+}
+
+impl<T> Mat3<T>
+where
+    T: Default + Copy + PartialEq
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// Returns the adjugate and the determinant of the matrix.
+    fn adjugate_determinant(&self) -> (Self, T)
+    {
+        let m = self;
+        let adjugate = MatN::from_mat(&[
+            [
+                m[1][1] * m[2][2] - m[1][2] * m[2][1],
+                m[0][2] * m[2][1] - m[0][1] * m[2][2],
+                m[0][1] * m[1][2] - m[0][2] * m[1][1],
+            ],
+            [
+                m[1][2] * m[2][0] - m[1][0] * m[2][2],
+                m[0][0] * m[2][2] - m[0][2] * m[2][0],
+                m[0][2] * m[1][0] - m[0][0] * m[1][2],
+            ],
+            [
+                m[1][0] * m[2][1] - m[1][1] * m[2][0],
+                m[0][1] * m[2][0] - m[0][0] * m[2][1],
+                m[0][0] * m[1][1] - m[0][1] * m[1][0],
+            ],
+        ]);
+        let determinant = m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+        (adjugate, determinant)
+    }
+
+    /// Computes the determinant of the matrix in closed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1, 2, 3], [0, 1, 4], [5, 6, 0]];
+    ///
+    /// assert_eq!(mat.determinant(), 1);
+    /// assert_eq!(mat.determinant() as f64, mat.determinant_lu().round());
+    /// ```
+    pub fn determinant(&self) -> T
+    {
+        self.adjugate_determinant().1
+    }
+
+    /// Computes the inverse of the matrix in closed form, ie the adjugate divided by the determinant.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrix, or `None` if the determinant is zero (up to rounding) or, for
+    /// integer matrices, if the inverse has non-integer elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+    ///
+    /// assert_eq!(mat.inverse().unwrap().to_mat(), [[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]]);
+    /// assert_eq!(mat3_raw![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].inverse(), None);
+    ///
+    /// // Singular, but rounding leaves a determinant of about `1.7e-17`.
+    /// let singular = mat3_raw![[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]];
+    /// assert_ne!(singular.determinant(), 0.0);
+    /// assert_eq!(singular.inverse(), None);
+    /// assert_eq!(singular.inverse_gauss_jordan(), None);
+    ///
+    /// // Scaling the matrix does not change the answer.
+    /// let tiny = Mat3::<f64>::identity().mul_scalar(1e-7);
+    /// assert!((tiny * tiny.inverse().unwrap()).is_identity(1e-10));
+    /// assert!(tiny.inverse_gauss_jordan().is_some());
+    ///
+    /// assert_eq!(mat3_raw![[1, 2, 3], [0, 1, 4], [5, 6, 0]].inverse().unwrap().to_mat(), [[-24, 18, 5], [20, -15, -4], [-5, 4, 1]]);
+    /// assert_eq!(mat3_raw![[2, 0, 0], [0, 3, 0], [0, 0, 4]].inverse(), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - A determinant with absolute value up to `N * epsilon * max_norm^N` is treated as zero, where
+    ///   `max_norm` is the largest absolute element. This matches the pivot tolerance of
+    ///   [`inverse_gauss_jordan`](MatN::inverse_gauss_jordan), so both paths reject the same
+    ///   rounding residues, and integer matrices are only singular with a determinant of zero.
+    /// - An integer matrix only has an integer inverse when its determinant divides every element
+    ///   of the adjugate, [`inverse_scaled`](Mat3::inverse_scaled) keeps the exact inverse otherwise.
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: PartialOrd + ExactDiv + Epsilon,
+    {
+        let (adjugate, determinant) = self.inverse_scaled()?;
+
+        divide_adjugate(self, &adjugate, determinant)
+    }
+
+    /// Computes the inverse of the matrix as an adjugate and a determinant, without dividing them.
+    ///
+    /// The inverse is `adjugate / determinant`, keeping both parts separate allows integer
+    /// matrices to stay exact until the division is really needed.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the adjugate and the determinant, or `None` if the determinant is exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat3_raw![[2, 0, 0], [0, 3, 0], [0, 0, 4]];
+    /// let (adjugate, determinant) = mat.inverse_scaled().unwrap();
+    ///
+    /// assert_eq!(determinant, 24);
+    /// assert_eq!(mat * adjugate, Mat3::identity().mul_scalar(determinant));
+    /// ```
+    pub fn inverse_scaled(&self) -> Option<(Self, T)>
+    {
+        let (adjugate, determinant) = self.adjugate_determinant();
+
+        if determinant == T::default()
+        {
+            return None;
+        }
+
+        Some((adjugate, determinant))
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Default + Copy + PartialEq
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// Returns the adjugate and the determinant of the matrix.
+    ///
+    /// Both are built from the 2x2 minors of the two upper rows (`s`) and the two lower rows (`c`).
+    fn adjugate_determinant(&self) -> (Self, T)
+    {
+        let m = self;
+
+        let s0 = m[0][0] * m[1][1] - m[1][0] * m[0][1];
+        let s1 = m[0][0] * m[1][2] - m[1][0] * m[0][2];
+        let s2 = m[0][0] * m[1][3] - m[1][0] * m[0][3];
+        let s3 = m[0][1] * m[1][2] - m[1][1] * m[0][2];
+        let s4 = m[0][1] * m[1][3] - m[1][1] * m[0][3];
+        let s5 = m[0][2] * m[1][3] - m[1][2] * m[0][3];
+
+        let c5 = m[2][2] * m[3][3] - m[3][2] * m[2][3];
+        let c4 = m[2][1] * m[3][3] - m[3][1] * m[2][3];
+        let c3 = m[2][1] * m[3][2] - m[3][1] * m[2][2];
+        let c2 = m[2][0] * m[3][3] - m[3][0] * m[2][3];
+        let c1 = m[2][0] * m[3][2] - m[3][0] * m[2][2];
+        let c0 = m[2][0] * m[3][1] - m[3][0] * m[2][1];
+
+        let adjugate = MatN::from_mat(&[
+            [
+                m[1][1] * c5 - m[1][2] * c4 + m[1][3] * c3,
+                m[0][2] * c4 - m[0][1] * c5 - m[0][3] * c3,
+                m[3][1] * s5 - m[3][2] * s4 + m[3][3] * s3,
+                m[2][2] * s4 - m[2][1] * s5 - m[2][3] * s3,
+            ],
+            [
+                m[1][2] * c2 - m[1][0] * c5 - m[1][3] * c1,
+                m[0][0] * c5 - m[0][2] * c2 + m[0][3] * c1,
+                m[3][2] * s2 - m[3][0] * s5 - m[3][3] * s1,
+                m[2][0] * s5 - m[2][2] * s2 + m[2][3] * s1,
+            ],
+            [
+                m[1][0] * c4 - m[1][1] * c2 + m[1][3] * c0,
+                m[0][1] * c2 - m[0][0] * c4 - m[0][3] * c0,
+                m[3][0] * s4 - m[3][1] * s2 + m[3][3] * s0,
+                m[2][1] * s2 - m[2][0] * s4 - m[2][3] * s0,
+            ],
+            [
+                m[1][1] * c1 - m[1][0] * c3 - m[1][2] * c0,
+                m[0][0] * c3 - m[0][1] * c1 + m[0][2] * c0,
+                m[3][1] * s1 - m[3][0] * s3 - m[3][2] * s0,
+                m[2][0] * s3 - m[2][1] * s1 + m[2][2] * s0,
+            ],
+        ]);
+        let determinant = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+        (adjugate, determinant)
+    }
+
+    /// Computes the determinant of the matrix in closed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat4_raw![[1, 0, 2, -1], [3, 0, 0, 5], [2, 1, 4, -3], [1, 0, 5, 0]];
+    ///
+    /// assert_eq!(mat.determinant(), 30);
+    /// assert_eq!(mat.determinant() as f64, mat.determinant_lu().round());
+    /// ```
+    pub fn determinant(&self) -> T
+    {
+        self.adjugate_determinant().1
+    }
+
+    /// Computes the inverse of the matrix in closed form, ie the adjugate divided by the determinant.
+    ///
+    /// # Returns
+    ///
+    /// The inverse of the matrix, or `None` if the determinant is zero (up to rounding) or, for
+    /// integer matrices, if the inverse has non-integer elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::<f64>::identity()
+    ///     .translate(&vec3![1.0, -2.0, 3.0])
+    ///     .rotate(0.7, &vec3![0.0, 1.0, 0.0])
+    ///     .scale(&vec3![2.0, 0.5, 4.0]);
+    ///
+    /// let closed_form = mat.inverse().unwrap();
+    /// let generic = mat.inverse_gauss_jordan().unwrap();
+    ///
+    /// for (a, b) in closed_form.iter_elements().zip(generic.iter_elements())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    ///
+    /// assert_eq!(Mat4::<f64>::new().inverse(), None);
+    ///
+    /// // Singular, the upper-left 3x3 block only has a rounding residue as determinant.
+    /// let singular = mat4_raw![[0.1, 0.2, 0.3, 0.0], [0.4, 0.5, 0.6, 0.0], [0.7, 0.8, 0.9, 0.0], [0.0, 0.0, 0.0, 1.0]];
+    /// assert_ne!(singular.determinant(), 0.0);
+    /// assert_eq!(singular.inverse(), None);
+    /// assert_eq!(singular.inverse_gauss_jordan(), None);
+    /// assert_eq!(mat4_raw![[1, 0, 2, -1], [3, 0, 0, 5], [2, 1, 4, -3], [1, 0, 5, 0]].inverse(), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - A determinant with absolute value up to `N * epsilon * max_norm^N` is treated as zero, where
+    ///   `max_norm` is the largest absolute element. This matches the pivot tolerance of
+    ///   [`inverse_gauss_jordan`](MatN::inverse_gauss_jordan), so both paths reject the same
+    ///   rounding residues, and integer matrices are only singular with a determinant of zero.
+    /// - An integer matrix only has an integer inverse when its determinant divides every element
+    ///   of the adjugate, [`inverse_scaled`](Mat4::inverse_scaled) keeps the exact inverse otherwise.
+    pub fn inverse(&self) -> Option<Self>
+    where
+        T: PartialOrd + ExactDiv + Epsilon,
+    {
+        let (adjugate, determinant) = self.inverse_scaled()?;
+
+        divide_adjugate(self, &adjugate, determinant)
+    }
+
+    /// Computes the inverse of the matrix as an adjugate and a determinant, without dividing them.
+    ///
+    /// The inverse is `adjugate / determinant`, keeping both parts separate allows integer
+    /// matrices to stay exact until the division is really needed.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the adjugate and the determinant, or `None` if the determinant is exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = mat4_raw![[1, 0, 2, -1], [3, 0, 0, 5], [2, 1, 4, -3], [1, 0, 5, 0]];
+    /// let (adjugate, determinant) = mat.inverse_scaled().unwrap();
+    ///
+    /// assert_eq!(determinant, 30);
+    /// assert_eq!(mat * adjugate, Mat4::identity().mul_scalar(determinant));
+    /// ```
+    pub fn inverse_scaled(&self) -> Option<(Self, T)>
+    {
+        let (adjugate, determinant) = self.adjugate_determinant();
+
+        if determinant == T::default()
+        {
+            return None;
+        }
+
+        Some((adjugate, determinant))
+    }
+
+    /// Computes the matrix that transforms normals, ie the inverse-transpose of the upper 3x3.
+    ///
+    /// Normals transformed by the model matrix itself stop being perpendicular to the surface
+    /// under non-uniform scale, the inverse-transpose keeps them perpendicular.
+    ///
+    /// # Returns
+    ///
+    /// The inverse-transpose of the upper-left 3x3 of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let model = Mat4::<f64>::identity()
+    ///     .translate(&vec3![5.0, 0.0, -1.0])
+    ///     .rotate(0.7, &vec3![0.0, 0.0, 1.0])
+    ///     .scale(&vec3![4.0, 1.0, 1.0]);
+    /// let normal_matrix = model.normal_matrix();
+    ///
+    /// // A surface with a tangent along `[1, 1, 0]` and its normal along `[1, -1, 0]`.
+    /// let tangent = model.mul_mat_vec(&vec3![1.0, 1.0, 0.0].to_homogeneous_direction()).from_homogeneous();
+    /// let normal = normal_matrix.mul_mat_vec(&vec3![1.0, -1.0, 0.0]);
+    ///
+    /// assert!(tangent.dot(&normal).abs() < 1e-12);
+    /// assert_eq!(Mat4::<f64>::identity().normal_matrix(), Mat3::identity());
+    /// ```
+    ///
+    /// An integer matrix without an integer inverse gets the cofactor matrix scaled by `|det|`,
+    /// here `det = -2`, so the normals still point the same way as with the inverse-transpose:
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let model = Mat4::<i32>::identity().scale(&vec3![-1, 2, 1]);
+    ///
+    /// assert_eq!(model.normal_matrix(), mat3_raw![[-2, 0, 0], [0, 1, 0], [0, 0, 2]]);
+    /// assert_eq!(model.normal_matrix().mul_mat_vec(&vec3![1, 0, 0]), vec3![-2, 0, 0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The translation of the matrix is ignored.
+    /// - The result is not normalized, transformed normals still need to be normalized.
+    /// - When the upper 3x3 has no inverse (it is singular, or an integer matrix without an integer
+    ///   inverse) the cofactor matrix (the transposed adjugate) times the sign of the determinant
+    ///   is returned instead, ie the inverse-transpose scaled by `|det|`. It maps normals to the
+    ///   same direction, only their length changes.
+    pub fn normal_matrix(&self) -> Mat3<T>
+    where
+        T: UnitValue + PartialOrd + ExactDiv + Epsilon,
+    {
+        let upper = Mat3::from_mat(&[
+            [self[0][0], self[0][1], self[0][2]],
+            [self[1][0], self[1][1], self[1][2]],
+            [self[2][0], self[2][1], self[2][2]],
+        ]);
+
+        match upper.inverse()
+        {
+            Some(inverse) => inverse.transpose(),
+            None =>
+            {
+                let (adjugate, determinant) = upper.adjugate_determinant();
+                let cofactor = adjugate.transpose();
+
+                // The cofactor matrix is `det * inverse^T`, a negative `det` would flip the normals.
+                if determinant < T::default()
+                {
+                    cofactor.map(|val| T::default() - val)
+                }
+                else
+                {
+                    cofactor
+                }
+            }
+        }
+    }
+
+    /// Transforms a point by the matrix and applies the perspective divide.
+    ///
+    /// With a projection (or view-projection) matrix this maps a world-space point into
+    /// normalized device coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to project, it is treated as `[x, y, z, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// The transformed point divided by its `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let projection = mat4_raw![
+    ///     [1.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, -2.0, -3.0],
+    ///     [0.0, 0.0, -1.0, 0.0]
+    /// ];
+    ///
+    /// assert_eq!(projection.project_point(&vec3![1.0, 2.0, -4.0]), vec3![0.25, 0.5, 1.25]);
+    /// // `w == 0`, the point is returned without the divide.
+    /// assert_eq!(projection.project_point(&vec3![1.0, 2.0, 0.0]), vec3![1.0, 2.0, -3.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - When the transformed `w` is zero the point lies on the plane of the eye and has no
+    ///   projection, `x`, `y` and `z` are returned without dividing, see [`VecN::from_homogeneous`].
+    pub fn project_point(&self, point: &Vec3<T>) -> Vec3<T>
+    where
+        T: UnitValue,
+    {
+        self.mul_mat_vec_scalar(&point.to_homogeneous_point()).from_homogeneous()
+    }
+
+    /// Maps a projected point back through the matrix, the inverse of [`Mat4::project_point`].
+    ///
+    /// With a projection (or view-projection) matrix this maps a point in normalized device
+    /// coordinates back into world space, unprojecting the same screen point at the near and far
+    /// depths gives the two ends of a picking ray.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The projected point, it is treated as `[x, y, z, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// The unprojected point, or `None` if the matrix has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let projection = mat4_raw![
+    ///     [1.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, -2.0, -3.0],
+    ///     [0.0, 0.0, -1.0, 0.0]
+    /// ];
+    /// let point = vec3![1.0, 2.0, -4.0];
+    /// let unprojected = projection.unproject_point(&projection.project_point(&point)).unwrap();
+    ///
+    /// assert!((unprojected - point).length() < 1e-12);
+    /// assert_eq!(Mat4::<f64>::new().unproject_point(&point), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The inverse is computed for every call, when unprojecting many points it is cheaper to
+    ///   compute [`Mat4::inverse`] once and use [`Mat4::project_point`] on it.
+    /// - When the transformed `w` is zero, `x`, `y` and `z` are returned without dividing.
+    pub fn unproject_point(&self, point: &Vec3<T>) -> Option<Vec3<T>>
+    where
+        T: UnitValue + PartialOrd + ExactDiv + Epsilon,
+    {
+        Some(self.inverse()?.project_point(point))
+    }
+}
\ No newline at end of file
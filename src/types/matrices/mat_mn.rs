@@ -0,0 +1,346 @@
+use std::ops::{IndexMut, Index, Add, Mul};
+use super::MatN;
+use crate::types::vectors::VecN;
+
+/// Generic object representing a mathematical matrix with `R` rows and `C` columns, with elements of type `T`.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of each element in the matrix.
+/// - `R`: The fixed number of rows of the matrix.
+/// - `C`: The fixed number of columns of the matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let empty_mat: MatMN<i32, 2, 3> = MatMN::new();
+/// let filled_mat: MatMN<f64, 3, 2> = MatMN::new_with(3.1415);
+///
+/// assert_eq!(empty_mat.to_mat(), [[0, 0, 0], [0, 0, 0]]);
+/// assert_eq!(filled_mat.to_mat(), [[3.1415, 3.1415], [3.1415, 3.1415], [3.1415, 3.1415]]);
+/// ```
+///
+/// # Notes
+///
+/// - Uses the type VecN as its rows.
+/// - Square matrices should use [`MatN`], which has a lot more operations, the two types
+///   convert into each other with `From`/`Into` when `R == C`.
+///
+/// # See Also
+///
+/// - [`VecN`].
+/// - [`MatN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatMN<T, const R: usize, const C: usize>
+where
+    T: Default + Copy,
+{
+    data: [VecN<T, C>; R]
+}
+impl<T, const R: usize, const C: usize> MatMN<T, R, C>
+where
+    T: Default + Copy,
+{
+    /// Creates a new instance of the `MatMN` object with default values for each element.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatMN` instance with elements initialized to their default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let mat = MatMN::<f64, 1, 2>::new();
+    ///
+    /// assert_eq!(mat.to_mat(), [[0.0, 0.0]]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The default value for each element is determined by the `Default` trait implementation for `T`.
+    pub fn new() -> Self
+    {
+        Self { data: [VecN::default(); R] }
+    }
+
+    /// Creates a new instance of the `MatMN` object with `value` as the value for each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to initialize the `MatMN` with.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatMN` instance with elements initialized to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = MatMN::<f64, 2, 3>::new_with(6.9);
+    ///
+    /// assert_eq!(mat.to_mat(), [[6.9, 6.9, 6.9], [6.9, 6.9, 6.9]]);
+    /// ```
+    pub const fn new_with(value: T) -> Self
+    {
+        Self { data: [VecN::new_with(value); R] }
+    }
+
+    /// This function constructs a new matrix using the rows from the provided array of `VecN` reference `data`.
+    ///
+    /// # arguments
+    ///
+    /// * `data` - a reference to an array containing the `VecN` rows of the matrix.
+    ///
+    /// # returns
+    ///
+    /// a new `MatMN` instance with elements copied from the provided array.
+    ///
+    /// # examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let array = [vec3![1, 2, 3], vec3![4, 5, 6]];
+    /// let mat = MatMN::from_mat_vec(&array);
+    ///
+    /// assert_eq!(mat.to_mat_vec(), &array);
+    /// ```
+    pub const fn from_mat_vec(data: &[VecN<T, C>; R]) -> Self
+    {
+        Self { data: *data }
+    }
+
+    /// This function constructs a new matrix using the elements from the provided 2D array reference `data`.
+    ///
+    /// # arguments
+    ///
+    /// * `data` - a reference to a 2D array with `R` rows of `C` elements.
+    ///
+    /// # returns
+    ///
+    /// a new `MatMN` instance with elements copied from the provided array.
+    ///
+    /// # examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let array = [[1, 2, 3], [4, 5, 6]];
+    /// let mat = MatMN::from_mat(&array);
+    ///
+    /// assert_eq!(mat.to_mat(), array);
+    /// ```
+    pub fn from_mat(data: &[[T; C]; R]) -> Self
+    {
+        let mut result = Self::new();
+
+        for (vec, other) in result.data.iter_mut().zip(data.iter())
+        {
+            *vec = VecN::from_array(other);
+        }
+
+        result
+    }
+
+    /// Returns a reference to the underlying array of rows.
+    pub fn to_mat_vec(&self) -> &[VecN<T, C>; R]
+    {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the underlying array of rows.
+    pub fn to_mut_mat_vec(&mut self) -> &mut [VecN<T, C>; R]
+    {
+        &mut self.data
+    }
+
+    /// Returns a `copy` of the underlying raw 2D array.
+    pub fn to_mat(&self) -> [[T; C]; R]
+    {
+        let mut result = [[T::default(); C]; R];
+
+        for (val, other) in result.iter_mut().zip(self.data.iter())
+        {
+            *val = *other.to_arr();
+        }
+
+        result
+    }
+
+    /// Transposes the matrix, swapping rows with columns.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatMN` with `C` rows and `R` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let mat = MatMN::from_mat(&[[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.transpose().to_mat(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    pub fn transpose(&self) -> MatMN<T, C, R>
+    {
+        let mut result = MatMN::new();
+
+        for i in 0..R {
+            for j in 0..C
+            {
+                result[j][i] = self[i][j];
+            }
+        }
+
+        result
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, VecN<T, C>>
+    {
+        self.data.iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, VecN<T, C>>
+    {
+        self.data.iter_mut()
+    }
+}
+
+impl<T, const R: usize, const C: usize> MatMN<T, R, C>
+where
+    T: Default + Copy
+        + std::ops::Mul<Output = T>
+        + std::ops::Add<Output = T>,
+{
+    /// Multiplies the matrix by a vector with `C` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The vector to multiply with the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with `R` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = MatMN::from_mat(&[[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.mul_mat_vec(&vec3![1, 0, 2]), vec2![7, 16]);
+    /// ```
+    pub fn mul_mat_vec(&self, vec: &VecN<T, C>) -> VecN<T, R>
+    {
+        let mut result = VecN::new();
+
+        for (i, vector) in self.data.iter().enumerate()
+        {
+            for (j, val) in vector.to_arr().iter().enumerate()
+            {
+                result[i] = result[i] + vec[j] * *val
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<usize> for MatMN<T, R, C>
+where
+    T: Default + Copy,
+{
+    type Output = VecN<T, C>;
+
+    fn index(&self, index: usize) -> &Self::Output
+    {
+        &self.data[index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for MatMN<T, R, C>
+where
+    T: Default + Copy,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output
+    {
+        &mut self.data[index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Default for MatMN<T, R, C>
+where
+    T: Default + Copy,
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Multiplies a `R x K` matrix by a `K x C` matrix, resulting in a `R x C` matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let a = MatMN::from_mat(&[[1, 2, 3], [4, 5, 6]]);
+/// let b = MatMN::from_mat(&[[7, 8], [9, 10], [11, 12]]);
+///
+/// assert_eq!((a * b).to_mat(), [[58, 64], [139, 154]]);
+/// assert_eq!((b * a).to_mat(), [[39, 54, 69], [49, 68, 87], [59, 82, 105]]);
+/// ```
+impl<T: Mul<Output = T>, const R: usize, const K: usize, const C: usize> Mul<MatMN<T, K, C>> for MatMN<T, R, K>
+where
+    T: Default + Copy + Add<Output = T>,
+{
+    type Output = MatMN<T, R, C>;
+
+    fn mul(self, rhs: MatMN<T, K, C>) -> Self::Output
+    {
+        let mut result = MatMN::new();
+
+        for i in 0..R {
+            for j in 0..C {
+                for k in 0..K
+                {
+                    result[i][j] = result[i][j] + self[i][k] * rhs[k][j];
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Converts a square `MatN` into a `MatMN`, and back.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mat = mat2_raw![[1, 2], [3, 4]];
+/// let mat_mn: MatMN<i32, 2, 2> = mat.into();
+///
+/// assert_eq!(mat_mn.to_mat(), [[1, 2], [3, 4]]);
+/// assert_eq!(Mat2::from(mat_mn), mat);
+/// ```
+impl<T, const N: usize> From<MatN<T, N>> for MatMN<T, N, N>
+where
+    T: Default + Copy,
+{
+    fn from(value: MatN<T, N>) -> Self
+    {
+        Self { data: *value.to_mat_vec() }
+    }
+}
+impl<T, const N: usize> From<MatMN<T, N, N>> for MatN<T, N>
+where
+    T: Default + Copy,
+{
+    fn from(value: MatMN<T, N, N>) -> Self
+    {
+        MatN::from_mat_vec(value.to_mat_vec())
+    }
+}
\ No newline at end of file
@@ -11,7 +11,7 @@ where
 {
     fn sum_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
 
         for val in result.data.iter_mut()
         {
@@ -22,7 +22,7 @@ where
     }
     fn sub_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
         
         for val in result.data.iter_mut()
         {
@@ -33,7 +33,7 @@ where
     }
     fn mul_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
         
         for val in result.data.iter_mut()
         {
@@ -44,7 +44,7 @@ where
     }    
     fn div_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
         
         for val in result.data.iter_mut()
         {
@@ -123,10 +123,50 @@ pub trait MatTransforms<T, const N: usize>
 where
     T: Default + Copy,
 {
-    /// Creates a `translation` matrix and multiplies with `self`, it is dependent on the matrix dimension. 
+    /// Creates a `translation` matrix and multiplies with `self`, it is dependent on the matrix dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat3::<i32>::identity()
+    ///     .translate(&vec2![1, 2])
+    ///     .translate(&vec2![3, 4]);
+    ///
+    /// assert_eq!(mat.mul_mat_vec(&vec3![0, 0, 1]).to_arr(), &[4, 6, 1]);
+    /// ```
     fn translate(&self, vec: &VecN<T, N>) -> Self;
     /// Creates a `rotation` matrix and multiplies with `self`, it is dependent on the matrix dimension.
+    ///
+    /// # Notes
+    ///
+    /// - The rotation is built around the 3D `axis`, which is not a 2D transform when `Self` is a `Mat3`.
+    ///   Use [`Mat3::rotate_2d`](super::Mat3::rotate_2d) to rotate 2D points instead.
     fn rotate(&self, angle: T, axis: &Vec3<T>) -> Self;
     /// Creates a `scaling` matrix and multiplies with `self`, it is dependent on the matrix dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = Mat4::<f64>::identity()
+    ///     .translate(&vec3![1.0, 0.0, 0.0])
+    ///     .rotate(std::f64::consts::FRAC_PI_2, &vec3![0.0, 0.0, 1.0])
+    ///     .scale(&vec3![2.0, 2.0, 2.0]);
+    ///
+    /// // The point is scaled, then rotated and finally translated.
+    /// let point = mat.mul_mat_vec(&vec4![1.0, 0.0, 0.0, 1.0]);
+    /// let expected = [1.0, 2.0, 0.0, 1.0];
+    ///
+    /// for (a, b) in point.iter().zip(expected.iter())
+    /// {
+    ///     assert!((a - b).abs() < 1e-10);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `translate`, `rotate` and `scale` all return `*self * generated_matrix`, so when the
+    ///   result multiplies a vector the last transform in a chain is applied first.
     fn scale(&self, values: &Vec3<T>) -> Self;
 }
\ No newline at end of file
@@ -0,0 +1,180 @@
+//! This file aims to integrate the types with the crate [rand](https://crates.io/crates/rand), it is only
+//! compiled with the `rand` feature enabled.
+
+use rand::{distributions::{Distribution, Standard, uniform::SampleUniform}, Rng};
+use super::{VecN, MatN};
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy,
+{
+    /// Creates a new `VecN` with every element sampled from the [`Standard`] distribution of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` instance with random elements, for float types each element is in `[0, 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let vec_a = Vec3::<f64>::random(&mut StdRng::seed_from_u64(42));
+    /// let vec_b = Vec3::<f64>::random(&mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(vec_a, vec_b);
+    /// assert!(vec_a.iter().all(|val| (0.0..1.0).contains(val)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Requires the `rand` feature.
+    /// - The same seeded `rng` always produces the same vector.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        let mut result = Self::new();
+
+        for val in result.data.iter_mut()
+        {
+            *val = rng.gen();
+        }
+
+        result
+    }
+
+    /// Creates a new `VecN` with every element sampled uniformly from the range `[min, max)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to sample from.
+    /// * `min` - The inclusive lower bound of each element.
+    /// * `max` - The exclusive upper bound of each element.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` instance with random elements in `[min, max)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let vec = Vec4::<f32>::random_range(&mut rng, -2.0, 2.0);
+    ///
+    /// assert!(vec.iter().all(|val| (-2.0..2.0).contains(val)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Requires the `rand` feature.
+    /// - Panics if `min >= max`, like [`Rng::gen_range`].
+    pub fn random_range<R: Rng + ?Sized>(rng: &mut R, min: T, max: T) -> Self
+    where
+        T: SampleUniform + PartialOrd,
+    {
+        let mut result = Self::new();
+
+        for val in result.data.iter_mut()
+        {
+            *val = rng.gen_range(min..max);
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize> MatN<T, N>
+where
+    T: Default + Copy,
+{
+    /// Creates a new `MatN` with every element sampled from the [`Standard`] distribution of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatN` instance with random elements, for float types each element is in `[0, 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mat_a = Mat4::<f32>::random(&mut StdRng::seed_from_u64(42));
+    /// let mat_b = Mat4::<f32>::random(&mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(mat_a, mat_b);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Requires the `rand` feature.
+    /// - The same seeded `rng` always produces the same matrix.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        let mut result = Self::new();
+
+        for vec in result.iter_mut()
+        {
+            *vec = VecN::random(rng);
+        }
+
+        result
+    }
+
+    /// Creates a new `MatN` with every element sampled uniformly from the range `[min, max)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to sample from.
+    /// * `min` - The inclusive lower bound of each element.
+    /// * `max` - The exclusive upper bound of each element.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatN` instance with random elements in `[min, max)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let mat = Mat3::<f64>::random_range(&mut rng, 10.0, 20.0);
+    ///
+    /// assert!(mat.iter().flat_map(|row| row.iter()).all(|val| (10.0..20.0).contains(val)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Requires the `rand` feature.
+    /// - Panics if `min >= max`, like [`Rng::gen_range`].
+    pub fn random_range<R: Rng + ?Sized>(rng: &mut R, min: T, max: T) -> Self
+    where
+        T: SampleUniform + PartialOrd,
+    {
+        let mut result = Self::new();
+
+        for vec in result.iter_mut()
+        {
+            *vec = VecN::random_range(rng, min, max);
+        }
+
+        result
+    }
+}
\ No newline at end of file
@@ -0,0 +1,182 @@
+//! This file holds the SIMD accelerated paths for `Vec4<f32>` and `Mat4<f32>`, it is only compiled
+//! with the `simd` feature enabled on `x86_64`, where SSE is always available.
+//!
+//! The operators and methods of `VecN`/`MatN` are generic, so every function here checks at the
+//! start if `T` is `f32` and `N` is 4 (comparing their `TypeId`s), returning `None` otherwise so the
+//! caller falls back to the scalar loop. This is why these paths need `T: 'static`, which the
+//! operators only require through `SimdScalar` when this module is compiled.
+//!
+//! The operations are performed in the same order as the scalar path, making the results
+//! bit-identical to it.
+//!
+//! # Examples
+//!
+//! ```
+//! # use vmm::*;
+//! let a = vec4![1.5_f32, -2.25, 3.1, 0.1];
+//! let b = vec4![0.3_f32, 7.0, -1e-3, 0.2];
+//! let mat = mat4_raw![
+//!     [0.1_f32, 0.2, 0.3, 0.4],
+//!     [1.1, -1.2, 1.3, -1.4],
+//!     [2.5, 2.5, 0.0, 1e6],
+//!     [-3.3, 3.3, 3.3, 3.3]
+//! ];
+//!
+//! for i in 0..4
+//! {
+//!     assert_eq!((a + b)[i].to_bits(), (a[i] + b[i]).to_bits());
+//!     assert_eq!((a - b)[i].to_bits(), (a[i] - b[i]).to_bits());
+//!     assert_eq!((a * b)[i].to_bits(), (a[i] * b[i]).to_bits());
+//!
+//!     let mut row = 0.0_f32;
+//!     for j in 0..4
+//!     {
+//!         row = row + b[j] * mat[i][j];
+//!     }
+//!     assert_eq!(mat.mul_mat_vec(&b)[i].to_bits(), row.to_bits());
+//! }
+//!
+//! let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+//! assert_eq!(a.dot(&b).to_bits(), dot.to_bits());
+//! ```
+
+use std::any::TypeId;
+use std::arch::x86_64::*;
+use super::{VecN, MatN};
+
+/// Returns `true` when `VecN<T, N>` is a `Vec4<f32>`.
+fn is_f32x4<T: 'static, const N: usize>() -> bool
+{
+    N == 4 && TypeId::of::<T>() == TypeId::of::<f32>()
+}
+
+/// Reinterprets the data of a `VecN` as `[f32; 4]`, must only be called after `is_f32x4` returns `true`.
+fn as_f32x4<T, const N: usize>(vec: &VecN<T, N>) -> &[f32; 4]
+where
+    T: Default + Copy + 'static,
+{
+    // SAFETY: `is_f32x4` guarantees `[T; N]` is `[f32; 4]`.
+    unsafe { &*(vec.data.as_ptr() as *const [f32; 4]) }
+}
+
+/// Reinterprets the data of a `VecN` as `[f32; 4]`, must only be called after `is_f32x4` returns `true`.
+fn as_mut_f32x4<T, const N: usize>(vec: &mut VecN<T, N>) -> &mut [f32; 4]
+where
+    T: Default + Copy + 'static,
+{
+    // SAFETY: `is_f32x4` guarantees `[T; N]` is `[f32; 4]`.
+    unsafe { &mut *(vec.data.as_mut_ptr() as *mut [f32; 4]) }
+}
+
+fn element_wise<T, const N: usize>(
+    lhs: &VecN<T, N>,
+    rhs: &VecN<T, N>,
+    op: unsafe fn(__m128, __m128) -> __m128,
+) -> Option<VecN<T, N>>
+where
+    T: Default + Copy + 'static,
+{
+    if !is_f32x4::<T, N>()
+    {
+        return None;
+    }
+
+    let mut result = *lhs;
+
+    // SAFETY: SSE is always available on x86_64 and the loads/stores are unaligned.
+    unsafe
+    {
+        let a = _mm_loadu_ps(as_f32x4(lhs).as_ptr());
+        let b = _mm_loadu_ps(as_f32x4(rhs).as_ptr());
+        _mm_storeu_ps(as_mut_f32x4(&mut result).as_mut_ptr(), op(a, b));
+    }
+
+    Some(result)
+}
+
+/// SIMD path for `Vec4<f32> + Vec4<f32>`.
+pub(crate) fn add<T, const N: usize>(lhs: &VecN<T, N>, rhs: &VecN<T, N>) -> Option<VecN<T, N>>
+where
+    T: Default + Copy + 'static,
+{
+    element_wise(lhs, rhs, _mm_add_ps)
+}
+
+/// SIMD path for `Vec4<f32> - Vec4<f32>`.
+pub(crate) fn sub<T, const N: usize>(lhs: &VecN<T, N>, rhs: &VecN<T, N>) -> Option<VecN<T, N>>
+where
+    T: Default + Copy + 'static,
+{
+    element_wise(lhs, rhs, _mm_sub_ps)
+}
+
+/// SIMD path for `Vec4<f32> * Vec4<f32>`.
+pub(crate) fn mul<T, const N: usize>(lhs: &VecN<T, N>, rhs: &VecN<T, N>) -> Option<VecN<T, N>>
+where
+    T: Default + Copy + 'static,
+{
+    element_wise(lhs, rhs, _mm_mul_ps)
+}
+
+/// SIMD path for `Vec4<f32>::dot`.
+///
+/// Only the products are computed in parallel, the sum is done lane by lane starting from
+/// `0.0` to keep the same rounding (and sign of zero) as the scalar path.
+pub(crate) fn dot<T, const N: usize>(lhs: &VecN<T, N>, rhs: &VecN<T, N>) -> Option<T>
+where
+    T: Default + Copy + 'static,
+{
+    let products = mul(lhs, rhs)?;
+    let lanes = as_f32x4(&products);
+    let sum = 0.0 + lanes[0] + lanes[1] + lanes[2] + lanes[3];
+
+    // SAFETY: `mul` only returns `Some` when `is_f32x4` is `true`, so `T` is `f32`.
+    Some(unsafe { std::mem::transmute_copy::<f32, T>(&sum) })
+}
+
+/// SIMD path for `Mat4<f32>::mul_mat_vec`.
+///
+/// Computes the result as the sum of the columns scaled by each element of `vec`, the
+/// additions for each row keep the same order as the scalar path.
+pub(crate) fn mul_mat_vec<T, const N: usize>(mat: &MatN<T, N>, vec: &VecN<T, N>) -> Option<VecN<T, N>>
+where
+    T: Default + Copy + 'static,
+{
+    if !is_f32x4::<T, N>()
+    {
+        return None;
+    }
+
+    let mut result = *vec;
+
+    // SAFETY: SSE is always available on x86_64 and `is_f32x4` guarantees `T` is `f32` and `N` is 4.
+    unsafe
+    {
+        let rows = [
+            _mm_loadu_ps(as_f32x4(&mat[0]).as_ptr()),
+            _mm_loadu_ps(as_f32x4(&mat[1]).as_ptr()),
+            _mm_loadu_ps(as_f32x4(&mat[2]).as_ptr()),
+            _mm_loadu_ps(as_f32x4(&mat[3]).as_ptr()),
+        ];
+        // Transposes the rows into columns.
+        let low_01 = _mm_unpacklo_ps(rows[0], rows[1]);
+        let high_01 = _mm_unpackhi_ps(rows[0], rows[1]);
+        let low_23 = _mm_unpacklo_ps(rows[2], rows[3]);
+        let high_23 = _mm_unpackhi_ps(rows[2], rows[3]);
+        let columns = [
+            _mm_movelh_ps(low_01, low_23),
+            _mm_movehl_ps(low_23, low_01),
+            _mm_movelh_ps(high_01, high_23),
+            _mm_movehl_ps(high_23, high_01),
+        ];
+
+        let mut acc = _mm_setzero_ps();
+        for (column, value) in columns.iter().zip(as_f32x4(vec).iter())
+        {
+            acc = _mm_add_ps(acc, _mm_mul_ps(_mm_set1_ps(*value), *column));
+        }
+        _mm_storeu_ps(as_mut_f32x4(&mut result).as_mut_ptr(), acc);
+    }
+
+    Some(result)
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
 pub mod math;
 pub use math::*;
 
-use std::ops::{IndexMut, Index, Add, Sub, Mul, Div};
+use std::ops::{IndexMut, Index, Add, Sub, Mul, Div, Neg};
+use crate::{FromF64, NumCast, UnitValue};
+use super::SimdScalar;
 
 /// Generic object representing a mathematical vector, with elements of type `T` and a fixed size `N`.
 ///
@@ -25,13 +27,54 @@ use std::ops::{IndexMut, Index, Add, Sub, Mul, Div};
 ///
 /// - The size of the vector is determined at compile time, providing safety against
 ///   accessing elements out of bounds, in addition to the speed.
+/// - `VecN` is `#[repr(transparent)]`, so it has the same memory layout as `[T; N]`.
+/// - `VecN` implements `Hash` when `T` does, so vectors of integers can be used as `HashMap` keys.
+///
+/// ```
+/// # use vmm::*;
+/// use std::collections::HashMap;
+///
+/// let mut grid = HashMap::new();
+/// grid.insert(vec2![3, -1], 'x');
+///
+/// assert_eq!(grid.get(&vec2![3, -1]), Some(&'x'));
+/// assert_eq!(grid.get(&vec2![-1, 3]), None);
+/// ```
+///
+/// - Only the methods that compute in `f64` require `f64: From<T>`, the rest of `VecN` and `MatN`
+///   work with any `Default + Copy` element type, like fixed-point or big number types.
+///
+/// ```
+/// # use vmm::*;
+/// use std::ops::{Add, Sub};
+///
+/// // A fixed-point number, which can't be converted into `f64` losslessly.
+/// #[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// struct Fixed(i64);
+///
+/// impl Add for Fixed { type Output = Self; fn add(self, rhs: Self) -> Self { Fixed(self.0 + rhs.0) } }
+/// impl Sub for Fixed { type Output = Self; fn sub(self, rhs: Self) -> Self { Fixed(self.0 - rhs.0) } }
+///
+/// let a = VecN::from_array(&[Fixed(1), Fixed(2)]);
+/// let b = VecN::from_array(&[Fixed(10), Fixed(20)]);
+///
+/// assert_eq!((a + b)[1], Fixed(22));
+/// assert_eq!((b - a)[0], Fixed(9));
+///
+/// let mut mat = MatN::from_mat(&[[Fixed(1), Fixed(2)], [Fixed(3), Fixed(4)]]);
+/// mat[(0, 1)] = Fixed(5);
+///
+/// assert_eq!((mat + mat)[(0, 1)], Fixed(10));
+/// assert_eq!((mat - mat).transpose(), MatN::new());
+/// ```
 ///
 /// # See Also
 ///
 /// - [`MatN`](super::matrices::MatN): Matrix type using vectors of fixed size (`VecN`) as rows.
 /// - [`Vec2`], [`Vec3`], [`Vec4`]: Specialized vector types
 ///   with fixed sizes for 2D, 3D and 4D. 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct VecN<T, const N: usize>
 where
     T: Default + Copy,
@@ -62,6 +105,8 @@ where
     ///
     /// - The default value for each element is determined by the `Default` trait implementation for `T`.
     /// - The size of the vector is fixed at compile time based on the constant `N`.
+    /// - This is not a `const fn` because `Default` can't be called in const context, use
+    ///   [`new_with`](VecN::new_with) or [`from_array`](VecN::from_array) instead.
     pub fn new() -> Self
     {
         Self { data: [T::default(); N] }
@@ -85,15 +130,88 @@ where
     /// let vec = VecN::<f64, 3>::new_with(6.9);
     /// 
     /// assert_eq!(vec.to_arr(), &[6.9, 6.9, 6.9]);
+    ///
+    /// const ONES: Vec4<i32> = VecN::new_with(1);
+    ///
+    /// assert_eq!(ONES.to_arr(), &[1, 1, 1, 1]);
     /// ```
     ///
     /// # Notes
     ///
     /// - The size of the vector is fixed at compile time based on the constant `N`.
-    pub fn new_with(value: T) -> Self
+    /// - This is a `const fn`, so it can be used to build `const` and `static` vectors.
+    ///
+    /// # See Also
+    ///
+    /// - [`splat`](VecN::splat): Alias of this function, named like in other math crates.
+    pub const fn new_with(value: T) -> Self
     {
         Self { data: [value; N] }
     }
+
+    /// Creates a new `VecN` with `value` as the value for each element, alias of [`new_with`](VecN::new_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = Vec3::splat(2.5);
+    ///
+    /// assert_eq!(vec, VecN::new_with(2.5));
+    /// assert_eq!(vec.to_arr(), &[2.5, 2.5, 2.5]);
+    /// ```
+    pub const fn splat(value: T) -> Self
+    {
+        Self::new_with(value)
+    }
+
+    /// Creates a new `VecN` with every element set to zero, ie the default value of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(Vec3::<f64>::zeros().to_arr(), &[0.0, 0.0, 0.0]);
+    /// ```
+    pub fn zeros() -> Self
+    {
+        Self::new()
+    }
+
+    /// Creates a new `VecN` with every element set to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(Vec3::<i32>::ones().to_arr(), &[1, 1, 1]);
+    /// ```
+    pub fn ones() -> Self
+    where
+        T: UnitValue,
+    {
+        Self::new_with(T::unit_value())
+    }
+
+    /// Creates a new `VecN` by calling `f` with the index of each element, like [`std::array::from_fn`].
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function returning the element at each index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(VecN::<i32, 4>::from_fn(|i| i as i32).to_arr(), &[0, 1, 2, 3]);
+    /// assert_eq!(Vec3::from_fn(|i| i as f64 * 0.5), vec3![0.0, 0.5, 1.0]);
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self { data: std::array::from_fn(f) }
+    }
     /// This function constructs a new vector of fixed size `N` using the elements from the provided
     /// array reference `data`. the elements of the array are copied to initialize the vector.
     ///
@@ -113,13 +231,20 @@ where
     /// let vec = VecN::from_array(&array);
     /// 
     /// assert_eq!(vec.to_arr(), &array);
+    ///
+    /// // Usable in const context.
+    /// const UP: Vec3<f32> = Vec3::from_array(&[0.0, 1.0, 0.0]);
+    /// const RIGHT: Vec3<f32> = vec3![1.0, 0.0, 0.0];
+    ///
+    /// assert_eq!(UP.cross(&RIGHT), vec3![0.0, 0.0, -1.0]);
     /// ```
     ///
     /// # notes
     ///
     /// - The size of the vector is fixed at compile time based on the constant `N`.
     /// - This function creates a new `VecN` with copied elements, leaving the original array unchanged.
-    pub fn from_array(data: &[T; N]) -> Self
+    /// - This is a `const fn`, so it can be used to build `const` and `static` vectors.
+    pub const fn from_array(data: &[T; N]) -> Self
     { 
         Self { data: *data }
     }
@@ -188,175 +313,1679 @@ where
     {
         &mut self.data
     }
-    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, T>
-    {
-        self.data.iter()
-    }
-    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, T>
+
+    /// Returns a copy of the underlying array.
+    ///
+    /// Unlike [`to_arr`](VecN::to_arr), which borrows the array, this returns an owned `[T; N]`
+    /// that can be moved into other APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec3![4, 2, 0];
+    /// let array: [i32; 3] = vec.to_array();
+    ///
+    /// assert_eq!(array, [4, 2, 0]);
+    /// assert_eq!(VecN::from_array(&array), vec);
+    /// ```
+    pub fn to_array(&self) -> [T; N]
     {
-        self.data.iter_mut()
+        self.data
     }
-}
-impl<T, const N: usize> Index<usize> for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = T;    
-    
-    fn index(&self, index: usize) -> &Self::Output 
+
+    /// Consumes the vector and returns the underlying array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let array = vec3![4, 2, 0].into_array();
+    ///
+    /// assert_eq!(array, [4, 2, 0]);
+    /// assert_eq!(VecN::from_array(&array).into_array(), array);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `VecN` is `Copy`, so this is the same as [`to_array`](VecN::to_array), it only makes
+    ///   the intent of a conversion clearer at the call site.
+    pub fn into_array(self) -> [T; N]
     {
-        &self.data[index]     
+        self.data
     }
-}
-impl<T, const N: usize> IndexMut<usize> for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output 
+
+    /// Returns the underlying array as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let vec = vec3![4, 2, 0];
+    /// 
+    /// assert_eq!(vec.as_slice(), &[4, 2, 0][..]);
+    /// ```
+    pub fn as_slice(&self) -> &[T]
     {
-        &mut self.data[index]        
+        &self.data[..]
     }
-}
-impl<T, const N: usize> Default for VecN<T, N>
-where 
-    T: Default + Copy,
-{
-    fn default() -> Self 
-    {
-        Self::new()     
-    } 
-}
 
-// Operator overlads
-impl<T: Add<Output = T>, const N: usize> Add for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
-    
-    fn add(self, rhs: Self) -> Self::Output 
+    /// Returns the underlying array as a mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let mut vec = vec3![4, 2, 0];
+    /// vec.as_mut_slice().reverse();
+    /// 
+    /// assert_eq!(vec.to_arr(), &[0, 2, 4]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T]
     {
-        let mut result = self.clone();  
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
-        {
-            *val = *val + *other;
-        }
-        
-        result
+        &mut self.data[..]
     }
-}
-impl<T: Sub<Output = T>, const N: usize> Sub for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
-    
-    fn sub(self, rhs: Self) -> Self::Output 
+
+    /// Returns a `Vec` with a copy of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// let vec = vec3![4, 2, 0];
+    /// 
+    /// assert_eq!(vec.to_vec(), vec![4, 2, 0]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
     {
-        let mut result = self.clone();  
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
-        {
-            *val = *val - *other;
-        }
-        
-        result
+        self.data.to_vec()
     }
-}
-impl<T: Mul<Output = T>, const N: usize> Mul for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
-    
-    fn mul(self, rhs: Self) -> Self::Output 
+    pub fn iter<'a>(&'a self) -> std::slice::Iter<'a, T>
     {
-        let mut result = self.clone();     
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
-        {
-            *val = *val * *other;
-        } 
-        
-        result
+        self.data.iter()
     }
-}
-impl<T: Div<Output = T>, const N: usize> Div for VecN<T, N>
-where
-    T: Default + Copy,
-{
-    type Output = Self;
-    
-    fn div(self, rhs: Self) -> Self::Output 
+    pub fn iter_mut<'a>(&'a mut self) -> std::slice::IterMut<'a, T>
     {
-        let mut result = self.clone();     
-        
-        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
-        {
-            *val = *val / *other;
-        } 
-        
-        result
+        self.data.iter_mut()
     }
-}
-
-pub type Vec2<T> = VecN<T, 2>;
-pub type Vec3<T> = VecN<T, 3>;
-pub type Vec4<T> = VecN<T, 4>;
 
-impl<T> Vec3<T> 
-where
-    T: Default + Copy
-    + std::ops::Mul<Output = T>
-    + std::ops::Sub<Output = T>,
-{
-    /// Computes the cross product of two 3D vectors.
+    /// Compares two `VecN` lexicographically, ie component by component.
     ///
-    /// The cross product of two 3D vectors results in a new vector that is perpendicular
-    /// to both input vectors. The cross product is computed as follows:
+    /// # Arguments
     ///
-    /// ```plaintext
-    /// | i  j  k  |
-    /// | a1 a2 a3 |
-    /// | b1 b2 b3 |
+    /// * `other` - The vector to compare with.
     ///
-    /// Result: [ (a2*b3 - a3*b2), (a3*b1 - a1*b3), (a1*b2 - a2*b1) ]
+    /// # Returns
+    ///
+    /// The [`Ordering`](std::cmp::Ordering) of the first pair of components that differ,
+    /// or `Equal` if all of them are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut vecs = vec![vec2![1, 3], vec2![0, 9], vec2![1, 2]];
+    /// vecs.sort_by(|a, b| a.lexicographic_cmp(b));
+    ///
+    /// assert_eq!(vecs, vec![vec2![0, 9], vec2![1, 2], vec2![1, 3]]);
+    /// assert_eq!(vec2![1, 2].lexicographic_cmp(&vec2![1, 3]), Ordering::Less);
     /// ```
     ///
+    /// # Notes
+    ///
+    /// - `VecN` doesn't implement `Ord`, since there is no natural ordering for vectors, this
+    ///   comparator should be used explicitly where an ordering is needed.
+    pub fn lexicographic_cmp(&self, other: &Self) -> std::cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.data.cmp(&other.data)
+    }
+
+    /// Compares two `VecN` lexicographically, for element types that are only `PartialOrd`, like floats.
+    ///
     /// # Arguments
     ///
-    /// * `other` - The second vector to compute the cross product with.
+    /// * `other` - The vector to compare with.
     ///
     /// # Returns
     ///
-    /// A new `Vec3` instance representing the cross product of the two vectors.
+    /// The ordering of the first pair of components that differ, `Some(Equal)` if all of them
+    /// are equal, or `None` if a pair of components can't be compared (ie `NaN`).
     ///
     /// # Examples
     ///
     /// ```
     /// # use vmm::*;
-    /// let vec1 = vec3![1.0, 2.0, 3.0];
-    /// let vec2 = vec3![4.0, 5.0, 6.0];
-    /// let result = vec1.cross(&vec2);
+    /// use std::cmp::Ordering;
     ///
-    /// assert_eq!(result.to_arr(), &[-3.0, 6.0, -3.0]); 
+    /// assert_eq!(vec2![1.0, 2.0].lexicographic_partial_cmp(&vec2![1.0, 3.0]), Some(Ordering::Less));
+    /// assert_eq!(vec2![1.0, 2.0].lexicographic_partial_cmp(&vec2![f64::NAN, 3.0]), None);
     /// ```
+    pub fn lexicographic_partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+    where
+        T: PartialOrd,
+    {
+        self.data.partial_cmp(&other.data)
+    }
+
+    /// Applies `f` to every element of the vector.
     ///
-    /// # Notes
+    /// # Arguments
+    ///
+    /// * `f` - The function called with each element.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` with the results of `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec3![1, -2, 3];
+    ///
+    /// assert_eq!(vec.map(|x| x * x), vec3![1, 4, 9]);
+    /// ```
+    pub fn map<F>(&self, mut f: F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = f(*val);
+        }
+
+        result
+    }
+
+    /// Combines the elements of two vectors pairwise with `f`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector providing the second argument of `f`.
+    /// * `f` - The function called with each pair of elements.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` where each element is `f(self[i], other[i])`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec3![1, 5, 3];
+    /// let b = vec3![4, 2, 3];
+    ///
+    /// assert_eq!(a.zip_with(&b, std::cmp::max), vec3![4, 5, 3]);
+    /// assert_eq!(a.zip_with(&b, |x, y| x * 10 + y), vec3![14, 52, 33]);
+    /// ```
+    pub fn zip_with<F>(&self, other: &Self, mut f: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let mut result = *self;
+
+        for (val, other) in result.data.iter_mut().zip(other.data.iter())
+        {
+            *val = f(*val, *other);
+        }
+
+        result
+    }
+
+    /// Folds every element of the vector into an accumulator, from the first to the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator.
+    /// * `f` - The function called with the accumulator and each element.
+    ///
+    /// # Returns
+    ///
+    /// The final value of the accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec4![2, 3, 4, 5];
+    ///
+    /// assert_eq!(vec.fold(1, |acc, x| acc * x), 120);
+    /// assert_eq!(vec.fold(0.0, |acc, x| acc + f64::from(x).ln()).exp().round(), 120.0);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.data.iter().copied().fold(init, f)
+    }
+
+    /// Reduces the elements of the vector into one, using the first element as the initial value.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function called with the accumulated value and each remaining element.
+    ///
+    /// # Returns
+    ///
+    /// The reduced value, or `None` if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec4![3, 9, -1, 4];
+    ///
+    /// assert_eq!(vec.reduce(std::cmp::max), Some(9));
+    /// assert_eq!(VecN::<i32, 0>::new().reduce(std::cmp::max), None);
+    /// ```
+    pub fn reduce<F>(&self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.data.iter().copied().reduce(f)
+    }
+
+    /// Converts every element of the vector into the numeric type `U`.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN<U, N>` with the converted elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1.5_f64, 2.5].cast::<f32>(), vec2![1.5_f32, 2.5]);
+    /// assert_eq!(vec3![1, -2, 3].cast::<f64>(), vec3![1.0, -2.0, 3.0]);
+    /// assert_eq!(vec2![1.9_f32, -1.9].cast::<i32>(), vec2![1, -1]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Behaves like an `as` cast on each element, see [`NumCast`].
+    pub fn cast<U>(&self) -> VecN<U, N>
+    where
+        T: NumCast<U>,
+        U: Default + Copy,
+    {
+        let mut result = VecN::new();
+
+        for (val, other) in result.data.iter_mut().zip(self.data.iter())
+        {
+            *val = other.num_cast();
+        }
+
+        result
+    }
+
+    /// Converts the vector into a vector with `M` elements.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN<T, M>` with the first `min(N, M)` elements copied from `self`, when growing
+    /// the remaining elements are `T::default()`, when shrinking the extra elements are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec3![1, 2, 3];
+    ///
+    /// assert_eq!(vec.resize::<5>(), VecN::from_array(&[1, 2, 3, 0, 0]));
+    /// assert_eq!(vec.resize::<2>(), vec2![1, 2]);
+    /// assert_eq!(vec.resize::<3>(), vec);
+    /// ```
+    pub fn resize<const M: usize>(&self) -> VecN<T, M>
+    {
+        let mut result = VecN::new();
+
+        for (val, other) in result.data.iter_mut().zip(self.data.iter())
+        {
+            *val = *other;
+        }
+
+        result
+    }
+
+    /// Computes the sign of every element of the vector.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with `-1` where the element is negative, `1` where it is positive and `0`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![-3.0, 0.0, 5.0].signum(), vec3![-1.0, 0.0, 1.0]);
+    /// assert_eq!(vec4![-7, 0, 2, i32::MIN].signum(), vec4![-1, 0, 1, -1]);
+    ///
+    /// let zero = vec2![-0.0, f64::NAN].signum();
+    /// assert!(zero[0] == 0.0 && zero[0].is_sign_positive());
+    /// assert_eq!(zero[1], 0.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Unlike [`f64::signum`], both `0.0` and `-0.0` give `0.0`, and `NaN` gives `0.0` too,
+    ///   since it is neither smaller nor bigger than zero.
+    pub fn signum(&self) -> Self
+    where
+        T: UnitValue + PartialOrd + Neg<Output = T>,
+    {
+        self.map(|val|
+        {
+            if val > T::default()
+            {
+                T::unit_value()
+            }
+            else if val < T::default()
+            {
+                -T::unit_value()
+            }
+            else
+            {
+                T::default()
+            }
+        })
+    }
+
+    /// Clamps every element of the vector into the same range `[min, max]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of every element.
+    /// * `max` - The upper bound of every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![-1.0, 0.5, 2.0].clamp_scalar(0.0, 1.0), vec3![0.0, 0.5, 1.0]);
+    /// assert_eq!(vec4![-7, 3, 12, 5].clamp_scalar(0, 10), vec4![0, 3, 10, 5]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Like the free function [`clamp`](crate::clamp), `NaN` is propagated and `min > max`
+    ///   doesn't panic, `min` is checked first.
+    pub fn clamp_scalar(&self, min: T, max: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.map(|val|
+        {
+            if val < min
+            {
+                min
+            }
+            else if val > max
+            {
+                max
+            }
+            else
+            {
+                val
+            }
+        })
+    }
+
+    /// Returns a copy of the vector with the element at `index` replaced by `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to replace.
+    /// * `value` - The new value of the element.
+    ///
+    /// # Returns
+    ///
+    /// A new vector equal to `self` except at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec3![1.0, 2.0, 3.0];
+    ///
+    /// assert_eq!(vec.with_component(1, 9.0), vec3![1.0, 9.0, 3.0]);
+    /// assert_eq!(vec.with_y(9.0), vec.with_component(1, 9.0));
+    /// assert_eq!(vec, vec3![1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn with_component(&self, index: usize, value: T) -> Self
+    {
+        assert!(index < N, "index {} is out of bounds for a vector of dimension {}", index, N);
+
+        let mut result = *self;
+        result.data[index] = value;
+
+        result
+    }
+
+    /// Compares each component of `self` with the matching component of `other`, ie `self < other`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Mask`] with `true` where the component of `self` is less than the one of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1.0, 5.0].cmplt(&vec2![3.0, 3.0]), vec2![true, false]);
+    /// assert_eq!(vec3![1, 3, 5].cmplt(&vec3![3, 3, 3]), vec3![true, false, false]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Comparisons with `NaN` are `false`.
+    pub fn cmplt(&self, other: &Self) -> Mask<N>
+    where
+        T: PartialOrd,
+    {
+        self.zip_mask(other, |a, b| a < b)
+    }
+
+    /// Compares each component of `self` with the matching component of `other`, ie `self > other`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Mask`] with `true` where the component of `self` is greater than the one of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1.0, 5.0].cmpgt(&vec2![3.0, 3.0]), vec2![false, true]);
+    /// assert_eq!(vec3![1, 3, 5].cmpgt(&vec3![3, 3, 3]), vec3![false, false, true]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Comparisons with `NaN` are `false`.
+    pub fn cmpgt(&self, other: &Self) -> Mask<N>
+    where
+        T: PartialOrd,
+    {
+        self.zip_mask(other, |a, b| a > b)
+    }
+
+    /// Compares each component of `self` with the matching component of `other`, ie `self == other`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Mask`] with `true` where the components are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![1, 3, 5].cmpeq(&vec3![3, 3, 3]), vec3![false, true, false]);
+    /// assert_eq!(vec2![f64::NAN, 0.0].cmpeq(&vec2![f64::NAN, -0.0]), vec2![false, true]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The comparison is exact, `NaN` is never equal to itself.
+    pub fn cmpeq(&self, other: &Self) -> Mask<N>
+    where
+        T: PartialEq,
+    {
+        self.zip_mask(other, |a, b| a == b)
+    }
+
+    /// Picks each component from `if_true` or `if_false`, following `mask`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask that selects the source of each component.
+    /// * `if_true` - The vector to take the components from where `mask` is `true`.
+    /// * `if_false` - The vector to take the components from where `mask` is `false`.
+    ///
+    /// # Returns
+    ///
+    /// A new vector blended from `if_true` and `if_false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec4![1, 2, 3, 4];
+    /// let b = vec4![10, 20, 30, 40];
+    ///
+    /// assert_eq!(VecN::select(&vec4![true, false, false, true], &a, &b), vec4![1, 20, 30, 4]);
+    ///
+    /// // A branchless elementwise minimum.
+    /// let c = vec4![5, 0, 5, 0];
+    /// assert_eq!(VecN::select(&a.cmplt(&c), &a, &c), vec4![1, 0, 3, 0]);
+    /// ```
+    pub fn select(mask: &Mask<N>, if_true: &Self, if_false: &Self) -> Self
+    {
+        let mut result = *if_false;
+
+        for (val, (selected, other)) in result.data.iter_mut().zip(mask.data.iter().zip(if_true.data.iter()))
+        {
+            if *selected
+            {
+                *val = *other;
+            }
+        }
+
+        result
+    }
+
+    fn zip_mask<F>(&self, other: &Self, mut f: F) -> Mask<N>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut result = Mask::new();
+
+        for (val, (a, b)) in result.data.iter_mut().zip(self.data.iter().zip(other.data.iter()))
+        {
+            *val = f(a, b);
+        }
+
+        result
+    }
+}
+impl<T, const N: usize> Index<usize> for VecN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = T;    
+    
+    fn index(&self, index: usize) -> &Self::Output 
+    {
+        &self.data[index]     
+    }
+}
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N>
+where
+    T: Default + Copy,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output 
+    {
+        &mut self.data[index]        
+    }
+}
+impl<T, const N: usize> Default for VecN<T, N>
+where 
+    T: Default + Copy,
+{
+    fn default() -> Self 
+    {
+        Self::new()     
+    } 
+}
+
+impl<T, const N: usize> TryFrom<&[T]> for VecN<T, N>
+where
+    T: Default + Copy,
+{
+    type Error = VecError;
+
+    /// Tries to create a `VecN` from a slice whose length is only known at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let data = vec![1.0, 2.0, 3.0];
+    ///
+    /// assert_eq!(Vec3::try_from(&data[..]), Ok(vec3![1.0, 2.0, 3.0]));
+    /// assert_eq!(Vec3::try_from(&data[..2]), Err(VecError::LengthMismatch { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> 
+    {
+        let data = value.try_into().map_err(|_| VecError::LengthMismatch {
+            expected: N,
+            found: value.len(),
+        })?;
+
+        Ok(Self { data })
+    }
+}
+
+/// Errors that can happen when building a `VecN` or a `MatN` from runtime data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VecError
+{
+    /// The number of elements provided does not match the dimension of the vector or matrix.
+    LengthMismatch { expected: usize, found: usize },
+}
+impl std::fmt::Display for VecError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        match self
+        {
+            VecError::LengthMismatch { expected, found } => 
+                write!(f, "expected {} elements, found {}", expected, found),
+        }
+    }
+}
+impl std::error::Error for VecError {}
+
+// Operator overlads
+/// Adds two vectors element by element.
+///
+/// The result is computed in place in the moved `self`, without an extra copy.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let a = vec3![1, 2, 3];
+/// let b = vec3![4, 5, 6];
+///
+/// assert_eq!(a + b, vec3![5, 7, 9]);
+/// // Both operands are `Copy`, so they are unchanged.
+/// assert_eq!((a, b), (vec3![1, 2, 3], vec3![4, 5, 6]));
+/// ```
+impl<T: Add<Output = T>, const N: usize> Add for VecN<T, N>
+where
+    T: Default + Copy + SimdScalar,
+{
+    type Output = Self;
+    
+    fn add(self, rhs: Self) -> Self::Output 
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(result) = crate::types::simd::add(&self, &rhs)
+        {
+            return result;
+        }
+
+        let mut result = self;
+        
+        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            *val = *val + *other;
+        }
+        
+        result
+    }
+}
+/// Subtracts two vectors element by element.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// assert_eq!(vec3![4, 5, 6] - vec3![1, 2, 3], vec3![3, 3, 3]);
+/// ```
+impl<T: Sub<Output = T>, const N: usize> Sub for VecN<T, N>
+where
+    T: Default + Copy + SimdScalar,
+{
+    type Output = Self;
+    
+    fn sub(self, rhs: Self) -> Self::Output 
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(result) = crate::types::simd::sub(&self, &rhs)
+        {
+            return result;
+        }
+
+        let mut result = self;
+        
+        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            *val = *val - *other;
+        }
+        
+        result
+    }
+}
+/// Multiplies two vectors element by element.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// assert_eq!(vec3![1, 2, 3] * vec3![4, 5, 6], vec3![4, 10, 18]);
+/// ```
+impl<T: Mul<Output = T>, const N: usize> Mul for VecN<T, N>
+where
+    T: Default + Copy + SimdScalar,
+{
+    type Output = Self;
+    
+    fn mul(self, rhs: Self) -> Self::Output 
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(result) = crate::types::simd::mul(&self, &rhs)
+        {
+            return result;
+        }
+
+        let mut result = self;
+        
+        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            *val = *val * *other;
+        } 
+        
+        result
+    }
+}
+/// Divides two vectors element by element.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// assert_eq!(vec3![8.0, 9.0, 1.0] / vec3![2.0, 3.0, 4.0], vec3![4.0, 3.0, 0.25]);
+/// ```
+impl<T: Div<Output = T>, const N: usize> Div for VecN<T, N>
+where
+    T: Default + Copy,
+{
+    type Output = Self;
+    
+    fn div(self, rhs: Self) -> Self::Output 
+    {
+        let mut result = self;
+        
+        for (val, other) in result.data.iter_mut().zip(rhs.data.iter())
+        {
+            *val = *val / *other;
+        } 
+        
+        result
+    }
+}
+
+pub type Vec2<T> = VecN<T, 2>;
+pub type Vec3<T> = VecN<T, 3>;
+pub type Vec4<T> = VecN<T, 4>;
+
+/// A per-component boolean mask, as produced by the elementwise comparisons like [`VecN::cmplt`]
+/// and consumed by [`VecN::select`].
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let mask: Mask<2> = vec2![1.0, 5.0].cmplt(&vec2![3.0, 3.0]);
+///
+/// assert_eq!(mask, vec2![true, false]);
+/// assert!(mask.iter().any(|&val| val));
+/// ```
+pub type Mask<const N: usize> = VecN<bool, N>;
+
+impl<T> From<(T, T)> for Vec2<T>
+where
+    T: Default + Copy,
+{
+    fn from(value: (T, T)) -> Self 
+    {
+        Self { data: [value.0, value.1] }
+    }
+}
+impl<T> From<Vec2<T>> for (T, T)
+where
+    T: Default + Copy,
+{
+    fn from(value: Vec2<T>) -> Self 
+    {
+        (value[0], value[1])
+    }
+}
+/// Converts a `(T, T, T)` tuple into a `Vec3`, the 2D and 4D versions work the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// let vec: Vec3<f32> = (1.0, 2.0, 3.0).into();
+/// let tuple: (f32, f32, f32) = vec.into();
+///
+/// assert_eq!(vec.to_arr(), &[1.0, 2.0, 3.0]);
+/// assert_eq!(tuple, (1.0, 2.0, 3.0));
+/// assert_eq!(Vec2::from((4, 2)), vec2![4, 2]);
+/// assert_eq!(<(i32, i32, i32, i32)>::from(vec4![1, 2, 3, 4]), (1, 2, 3, 4));
+/// ```
+impl<T> From<(T, T, T)> for Vec3<T>
+where
+    T: Default + Copy,
+{
+    fn from(value: (T, T, T)) -> Self 
+    {
+        Self { data: [value.0, value.1, value.2] }
+    }
+}
+impl<T> From<Vec3<T>> for (T, T, T)
+where
+    T: Default + Copy,
+{
+    fn from(value: Vec3<T>) -> Self 
+    {
+        (value[0], value[1], value[2])
+    }
+}
+impl<T> From<(T, T, T, T)> for Vec4<T>
+where
+    T: Default + Copy,
+{
+    fn from(value: (T, T, T, T)) -> Self 
+    {
+        Self { data: [value.0, value.1, value.2, value.3] }
+    }
+}
+impl<T> From<Vec4<T>> for (T, T, T, T)
+where
+    T: Default + Copy,
+{
+    fn from(value: Vec4<T>) -> Self 
+    {
+        (value[0], value[1], value[2], value[3])
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Default + Copy,
+{
+    /// Creates a unit `Vec2` pointing in the direction of `radians`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` - The angle of the direction, counter-clockwise from the positive x axis.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec2` with the value `[cos(radians), sin(radians)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let right = Vec2::<f64>::from_angle(0.0);
+    /// let up = Vec2::<f64>::from_angle(to_radians(90.0));
+    ///
+    /// assert_eq!(right.to_arr(), &[1.0, 0.0]);
+    /// assert!(up[0].abs() < 1e-10 && (up[1] - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`angle`](Vec2::angle): The inverse of this function for unit vectors.
+    pub fn from_angle(radians: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let (sin, cos) = radians.sin_cos();
+
+        Self { data: [T::from_f64(cos), T::from_f64(sin)] }
+    }
+
+    /// Computes the angle of the vector, counter-clockwise from the positive x axis.
+    ///
+    /// # Returns
+    ///
+    /// The angle in radians, in the range `[-PI, PI]`, computed with `atan2(y, x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1.0, 0.0].angle(), 0.0);
+    /// assert_eq!(vec2![0, 2].angle(), to_radians(90.0));
+    /// assert_eq!(vec2![-1.0, 0.0].angle(), std::f64::consts::PI);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`from_angle`](Vec2::from_angle): The inverse of this function.
+    pub fn angle(&self) -> f64
+    where
+        f64: From<T>,
+    {
+        f64::from(self[1]).atan2(f64::from(self[0]))
+    }
+
+    /// Computes the signed angle from `self` to `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to measure the angle to.
+    ///
+    /// # Returns
+    ///
+    /// The angle in radians, in the range `[-PI, PI]`, positive when `other` is counter-clockwise
+    /// from `self`, computed with `atan2(perp_dot, dot)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let x = vec2![1.0, 0.0];
+    /// let y = vec2![0.0, 1.0];
+    ///
+    /// assert_eq!(x.angle_between_signed(&y), to_radians(90.0));
+    /// assert_eq!(y.angle_between_signed(&x), -to_radians(90.0));
+    /// assert_eq!(vec2![2, 0].angle_between_signed(&vec2![-3, 0]), std::f64::consts::PI);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The vectors don't need to be normalized.
+    /// - When either vector is zero the angle is `0`.
+    pub fn angle_between_signed(&self, other: &Self) -> f64
+    where
+        f64: From<T>,
+    {
+        let [ax, ay] = self.data.map(f64::from);
+        let [bx, by] = other.data.map(f64::from);
+
+        (ax * by - ay * bx).atan2(ax * bx + ay * by)
+    }
+
+    /// Rotates the point `self` around `pivot`.
+    ///
+    /// The point is translated so that `pivot` is the origin, rotated, and translated back.
+    ///
+    /// # Arguments
+    ///
+    /// * `pivot` - The point to rotate around.
+    /// * `angle` - The rotation angle in radians, counter-clockwise.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec2` with the rotated point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let point = vec2![3.0, 1.0];
+    /// let rotated = point.rotate_around(&vec2![1.0, 1.0], to_radians(90.0));
+    ///
+    /// assert!((rotated - vec2![1.0, 3.0]).length() < 1e-12);
+    /// assert_eq!(point.rotate_around(&point, 1.0), point);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The rotation is computed in `f64` and converted back into `T`.
+    pub fn rotate_around(&self, pivot: &Self, angle: f64) -> Self
+    where
+        T: FromF64,
+        f64: From<T>,
+    {
+        let [px, py] = pivot.data.map(f64::from);
+        let [x, y] = self.data.map(f64::from);
+        let (dx, dy) = (x - px, y - py);
+        let (sin, cos) = angle.sin_cos();
+
+        Self { data: [T::from_f64(px + dx * cos - dy * sin), T::from_f64(py + dx * sin + dy * cos)] }
+    }
+
+    /// Returns a copy of the vector with `x` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1, 2].with_x(9), vec2![9, 2]);
+    /// ```
+    pub fn with_x(&self, value: T) -> Self
+    {
+        self.with_component(0, value)
+    }
+
+    /// Returns a copy of the vector with `y` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1, 2].with_y(9), vec2![1, 9]);
+    /// ```
+    pub fn with_y(&self, value: T) -> Self
+    {
+        self.with_component(1, value)
+    }
+
+    /// Computes the barycentric coordinates of the point `p` in the triangle `abc`.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The point to compute the coordinates of.
+    /// * `a`, `b`, `c` - The vertices of the triangle.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec3` with the weights of `a`, `b` and `c`, which sum to one. Interpolating any value
+    /// stored at the vertices with these weights gives the value at `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec2![0.0, 0.0];
+    /// let b = vec2![4.0, 0.0];
+    /// let c = vec2![0.0, 4.0];
+    ///
+    /// assert_eq!(Vec2::barycentric(&a, &a, &b, &c), vec3![1.0, 0.0, 0.0]);
+    /// assert_eq!(Vec2::barycentric(&vec2![1.0, 2.0], &a, &b, &c), vec3![0.25, 0.25, 0.5]);
+    /// assert_eq!(Vec2::barycentric(&a, &a, &b, &vec2![8.0, 0.0]), vec3![0.0, 0.0, 0.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The weights are the ratios between the signed areas of the sub-triangles and `abc`,
+    ///   computed with 2D cross products.
+    /// - Points outside of the triangle have at least one negative weight.
+    /// - A degenerate (zero area) triangle returns the zero vector, which is not a valid set of weights.
+    /// - The coordinates are computed in `f64` and converted back into `T`.
+    pub fn barycentric(p: &Self, a: &Self, b: &Self, c: &Self) -> Vec3<T>
+    where
+        T: FromF64,
+        f64: From<T>,
+    {
+        let to_f64 = |vec: &Self| [f64::from(vec[0]), f64::from(vec[1])];
+        let perp_dot = |u: [f64; 2], v: [f64; 2]| u[0] * v[1] - u[1] * v[0];
+        let sub = |u: [f64; 2], v: [f64; 2]| [u[0] - v[0], u[1] - v[1]];
+
+        let (p, a, b, c) = (to_f64(p), to_f64(a), to_f64(b), to_f64(c));
+        let area = perp_dot(sub(b, a), sub(c, a));
+
+        if area == 0.0
+        {
+            return VecN::new();
+        }
+
+        let v = perp_dot(sub(p, a), sub(c, a)) / area;
+        let w = perp_dot(sub(b, a), sub(p, a)) / area;
+
+        VecN { data: [T::from_f64(1.0 - v - w), T::from_f64(v), T::from_f64(w)] }
+    }
+}
+
+impl<T> Vec3<T> 
+where
+    T: Default + Copy
+    + std::ops::Mul<Output = T>
+    + std::ops::Sub<Output = T>,
+{
+    /// Computes the cross product of two 3D vectors.
+    ///
+    /// The cross product of two 3D vectors results in a new vector that is perpendicular
+    /// to both input vectors. The cross product is computed as follows:
+    ///
+    /// ```plaintext
+    /// | i  j  k  |
+    /// | a1 a2 a3 |
+    /// | b1 b2 b3 |
+    ///
+    /// Result: [ (a2*b3 - a3*b2), (a3*b1 - a1*b3), (a1*b2 - a2*b1) ]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second vector to compute the cross product with.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec3` instance representing the cross product of the two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec1 = vec3![1.0, 2.0, 3.0];
+    /// let vec2 = vec3![4.0, 5.0, 6.0];
+    /// let result = vec1.cross(&vec2);
+    ///
+    /// assert_eq!(result.to_arr(), &[-3.0, 6.0, -3.0]); 
+    /// ```
+    ///
+    /// # Notes
     ///
     /// - Both input `VecN` and the resulting `VecN` are in 3D space, ie `Vec3`.
     ///
-    /// # See Also
+    /// # See Also
+    ///
+    /// - [`Vec3`]: The 3D vector type used by this method.
+    pub fn cross(&self, other: &Self) -> Self
+    {
+        Self { data: 
+        [
+            self[1]*other[2] - self[2]*other[1],
+            self[2]*other[0] - self[0]*other[2],
+            self[0]*other[1] - self[1]*other[0]
+        ]}
+    }
+
+    /// Computes the cross product of `self` and `other`, storing the result in `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second vector to compute the cross product with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut vec = vec3![1.0, 2.0, 3.0];
+    /// let other = vec3![4.0, 5.0, 6.0];
+    /// let expected = vec.cross(&other);
+    /// vec.cross_assign(&other);
+    ///
+    /// assert_eq!(vec, expected);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Every component of the result depends on the other components of `self`, so the
+    ///   product is computed into a temporary and then copied.
+    pub fn cross_assign(&mut self, other: &Self)
+    {
+        *self = self.cross(other);
+    }
+
+    /// Computes the scalar triple product `self · (b × c)`.
+    ///
+    /// The result is the signed volume of the parallelepiped spanned by the three vectors,
+    /// it is positive when they form a right-handed system.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The second vector.
+    /// * `c` - The third vector.
+    ///
+    /// # Returns
+    ///
+    /// The scalar triple product of the three vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let x = vec3![1.0, 0.0, 0.0];
+    /// let y = vec3![0.0, 1.0, 0.0];
+    /// let z = vec3![0.0, 0.0, 1.0];
+    ///
+    /// assert_eq!(x.scalar_triple(&y, &z), 1.0);
+    /// assert_eq!(y.scalar_triple(&x, &z), -1.0);
+    /// assert_eq!(vec3![2, 0, 0].scalar_triple(&vec3![0, 3, 0], &vec3![1, 1, 4]), 24);
+    /// ```
+    pub fn scalar_triple(&self, b: &Self, c: &Self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        let cross = b.cross(c);
+
+        self[0]*cross[0] + self[1]*cross[1] + self[2]*cross[2]
+    }
+
+    /// Computes the vector triple product `self × (b × c)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The second vector.
+    /// * `c` - The third vector.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec3` with the vector triple product, which lies in the plane spanned by `b` and `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec3![1.0, 2.0, 3.0];
+    /// let b = vec3![4.0, 5.0, 6.0];
+    /// let c = vec3![7.0, 8.0, 10.0];
+    ///
+    /// // a × (b × c) = b(a · c) - c(a · b)
+    /// let expected = b.mul_scalar(a.dot(&c)) - c.mul_scalar(a.dot(&b));
+    ///
+    /// assert_eq!(a.vector_triple(&b, &c), expected);
+    /// ```
+    pub fn vector_triple(&self, b: &Self, c: &Self) -> Self
+    {
+        self.cross(&b.cross(c))
+    }
+}    
+
+impl<T> Vec3<T>
+where
+    T: Default + Copy,
+{
+    /// Converts a 3D point into homogeneous coordinates, ie `w = 1`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec4` with the value `[x, y, z, 1]`.
+    ///
+    /// # Examples
     ///
-    /// - [`Vec3`]: The 3D vector type used by this method.
-    pub fn cross(&self, other: &Self) -> Self
+    /// ```
+    /// # use vmm::*;
+    /// let point = vec3![1.0, 2.0, 3.0];
+    /// let mat = Mat4::identity().translate(&vec3![10.0, 0.0, 0.0]);
+    ///
+    /// assert_eq!(point.to_homogeneous_point(), vec4![1.0, 2.0, 3.0, 1.0]);
+    /// assert_eq!(mat.mul_mat_vec(&point.to_homogeneous_point()).from_homogeneous(), vec3![11.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Points are affected by the translation of a `Mat4`.
+    pub fn to_homogeneous_point(&self) -> Vec4<T>
+    where
+        T: UnitValue,
     {
-        Self { data: 
-        [
-            self[1]*other[2] - self[2]*other[1],
-            self[2]*other[0] - self[0]*other[2],
-            self[0]*other[1] - self[1]*other[0]
-        ]}
+        VecN { data: [self[0], self[1], self[2], T::unit_value()] }
+    }
+
+    /// Converts a 3D direction into homogeneous coordinates, ie `w = 0`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec4` with the value `[x, y, z, 0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let direction = vec3![1.0, 2.0, 3.0];
+    /// let mat = Mat4::identity().translate(&vec3![10.0, 0.0, 0.0]);
+    ///
+    /// assert_eq!(direction.to_homogeneous_direction(), vec4![1.0, 2.0, 3.0, 0.0]);
+    /// assert_eq!(mat.mul_mat_vec(&direction.to_homogeneous_direction()).from_homogeneous(), direction);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Directions are not affected by the translation of a `Mat4`.
+    pub fn to_homogeneous_direction(&self) -> Vec4<T>
+    {
+        VecN { data: [self[0], self[1], self[2], T::default()] }
+    }
+
+    /// Sets the `x` and `y` components from a `Vec2`, leaving `z` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut position = vec3![1, 2, 3];
+    /// position.set_xy(&vec2![7, 8]);
+    ///
+    /// assert_eq!(position, vec3![7, 8, 3]);
+    /// ```
+    pub fn set_xy(&mut self, xy: &Vec2<T>)
+    {
+        self.data[..2].copy_from_slice(xy.as_slice());
+    }
+
+    /// Returns a copy of the vector with `x` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![1, 2, 3].with_x(9), vec3![9, 2, 3]);
+    /// ```
+    pub fn with_x(&self, value: T) -> Self
+    {
+        self.with_component(0, value)
+    }
+
+    /// Returns a copy of the vector with `y` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![1, 2, 3].with_y(9), vec3![1, 9, 3]);
+    /// ```
+    pub fn with_y(&self, value: T) -> Self
+    {
+        self.with_component(1, value)
+    }
+
+    /// Returns a copy of the vector with `z` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![1, 2, 3].with_z(9), vec3![1, 2, 9]);
+    /// ```
+    pub fn with_z(&self, value: T) -> Self
+    {
+        self.with_component(2, value)
+    }
+
+    /// Returns the red channel of a color, ie the component at index `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![0.1, 0.5, 0.9].r(), 0.1);
+    /// ```
+    pub fn r(&self) -> T
+    {
+        self.data[0]
+    }
+
+    /// Returns the green channel of a color, ie the component at index `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![0.1, 0.5, 0.9].g(), 0.5);
+    /// ```
+    pub fn g(&self) -> T
+    {
+        self.data[1]
+    }
+
+    /// Returns the blue channel of a color, ie the component at index `2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![0.1, 0.5, 0.9].b(), 0.9);
+    /// ```
+    pub fn b(&self) -> T
+    {
+        self.data[2]
+    }
+
+    /// Computes the barycentric coordinates of the point `p` in the triangle `abc`.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The point to compute the coordinates of.
+    /// * `a`, `b`, `c` - The vertices of the triangle.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec3` with the weights of `a`, `b` and `c`, which sum to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec3![0.0, 0.0, 1.0];
+    /// let b = vec3![4.0, 0.0, 1.0];
+    /// let c = vec3![0.0, 4.0, 1.0];
+    ///
+    /// assert_eq!(Vec3::barycentric(&c, &a, &b, &c), vec3![0.0, 0.0, 1.0]);
+    /// assert_eq!(Vec3::barycentric(&vec3![1.0, 2.0, 1.0], &a, &b, &c), vec3![0.25, 0.25, 0.5]);
+    /// // Points off the plane of the triangle are projected onto it.
+    /// assert_eq!(Vec3::barycentric(&vec3![1.0, 2.0, 9.0], &a, &b, &c), vec3![0.25, 0.25, 0.5]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `p` is projected onto the plane of the triangle.
+    /// - Points outside of the triangle have at least one negative weight.
+    /// - A degenerate (zero area) triangle returns the zero vector, which is not a valid set of weights.
+    /// - The coordinates are computed in `f64` and converted back into `T`.
+    pub fn barycentric(p: &Self, a: &Self, b: &Self, c: &Self) -> Vec3<T>
+    where
+        T: FromF64,
+        f64: From<T>,
+    {
+        let sub = |u: &Self, v: &Self| [0, 1, 2].map(|i| f64::from(u[i]) - f64::from(v[i]));
+        let dot = |u: [f64; 3], v: [f64; 3]| u[0] * v[0] + u[1] * v[1] + u[2] * v[2];
+
+        let (ab, ac, ap) = (sub(b, a), sub(c, a), sub(p, a));
+        let (d00, d01, d11) = (dot(ab, ab), dot(ab, ac), dot(ac, ac));
+        let (d20, d21) = (dot(ap, ab), dot(ap, ac));
+        let denominator = d00 * d11 - d01 * d01;
+
+        if denominator == 0.0
+        {
+            return VecN::new();
+        }
+
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+
+        VecN { data: [T::from_f64(1.0 - v - w), T::from_f64(v), T::from_f64(w)] }
+    }
+
+    /// Computes two unit vectors that, together with `self`, form an orthonormal basis.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(tangent, bitangent)` of unit vectors perpendicular to `self` and to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let (tangent, bitangent) = vec3![0.0, 0.0, 1.0].any_orthonormal_pair();
+    /// assert_eq!(tangent, vec3![1.0, 0.0, 0.0]);
+    /// assert_eq!(bitangent, vec3![0.0, 1.0, 0.0]);
+    ///
+    /// let normal = vec3![1.0_f64, -2.0, 3.0].normalize();
+    /// let (tangent, bitangent) = normal.any_orthonormal_pair();
+    ///
+    /// assert!(normal.dot(&tangent).abs() < 1e-12);
+    /// assert!(normal.dot(&bitangent).abs() < 1e-12);
+    /// assert!(tangent.dot(&bitangent).abs() < 1e-12);
+    /// assert!((tangent.length() - 1.0).abs() < 1e-12);
+    /// assert!((bitangent.length() - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `self` is expected to be normalized.
+    /// - Uses the construction from Duff et al., "Building an Orthonormal Basis, Revisited",
+    ///   which stays stable for every direction, including `-z`.
+    /// - The vectors are computed in `f64` and converted back into `T`.
+    pub fn any_orthonormal_pair(&self) -> (Self, Self)
+    where
+        T: FromF64,
+        f64: From<T>,
+    {
+        let [x, y, z] = self.data.map(f64::from);
+        let sign = 1.0_f64.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+
+        let tangent = [1.0 + sign * x * x * a, sign * b, -sign * x];
+        let bitangent = [b, sign + y * y * a, -y];
+
+        (VecN { data: tangent.map(T::from_f64) }, VecN { data: bitangent.map(T::from_f64) })
+    }
+}
+
+impl<T> Vec4<T>
+where
+    T: Default + Copy,
+{
+    /// Converts homogeneous coordinates back into 3D, dividing `x`, `y` and `z` by `w`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Vec3` with the value `[x / w, y / w, z / w]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![2.0, 4.0, 6.0, 2.0].from_homogeneous(), vec3![1.0, 2.0, 3.0]);
+    /// assert_eq!(vec4![2.0, 4.0, 6.0, 0.0].from_homogeneous(), vec3![2.0, 4.0, 6.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - When `w == 0` the vector is a direction (or a point at infinity), so `x`, `y` and `z` are
+    ///   returned unchanged instead of dividing by zero.
+    pub fn from_homogeneous(&self) -> Vec3<T>
+    where
+        T: PartialEq + Div<Output = T>,
+    {
+        let w = self[3];
+
+        if w == T::default()
+        {
+            return VecN { data: [self[0], self[1], self[2]] };
+        }
+
+        VecN { data: [self[0] / w, self[1] / w, self[2] / w] }
+    }
+
+    /// Sets the `x` and `y` components from a `Vec2`, leaving `z` and `w` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut vec = vec4![1, 2, 3, 4];
+    /// vec.set_xy(&vec2![7, 8]);
+    ///
+    /// assert_eq!(vec, vec4![7, 8, 3, 4]);
+    /// ```
+    pub fn set_xy(&mut self, xy: &Vec2<T>)
+    {
+        self.data[..2].copy_from_slice(xy.as_slice());
+    }
+
+    /// Sets the `x`, `y` and `z` components from a `Vec3`, leaving `w` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mut point = vec4![1.0, 2.0, 3.0, 1.0];
+    /// point.set_xyz(&vec3![4.0, 5.0, 6.0]);
+    ///
+    /// assert_eq!(point, vec4![4.0, 5.0, 6.0, 1.0]);
+    /// ```
+    pub fn set_xyz(&mut self, xyz: &Vec3<T>)
+    {
+        self.data[..3].copy_from_slice(xyz.as_slice());
+    }
+
+    /// Returns a copy of the vector with `x` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![1, 2, 3, 4].with_x(9), vec4![9, 2, 3, 4]);
+    /// ```
+    pub fn with_x(&self, value: T) -> Self
+    {
+        self.with_component(0, value)
+    }
+
+    /// Returns a copy of the vector with `y` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![1, 2, 3, 4].with_y(9), vec4![1, 9, 3, 4]);
+    /// ```
+    pub fn with_y(&self, value: T) -> Self
+    {
+        self.with_component(1, value)
+    }
+
+    /// Returns a copy of the vector with `z` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![1, 2, 3, 4].with_z(9), vec4![1, 2, 9, 4]);
+    /// ```
+    pub fn with_z(&self, value: T) -> Self
+    {
+        self.with_component(2, value)
+    }
+
+    /// Returns a copy of the vector with `w` replaced by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![1, 2, 3, 4].with_w(9), vec4![1, 2, 3, 9]);
+    /// ```
+    pub fn with_w(&self, value: T) -> Self
+    {
+        self.with_component(3, value)
+    }
+
+    /// Returns the red channel of a color, ie the component at index `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![0.1, 0.5, 0.9, 1.0].r(), 0.1);
+    /// ```
+    pub fn r(&self) -> T
+    {
+        self.data[0]
+    }
+
+    /// Returns the green channel of a color, ie the component at index `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![0.1, 0.5, 0.9, 1.0].g(), 0.5);
+    /// ```
+    pub fn g(&self) -> T
+    {
+        self.data[1]
+    }
+
+    /// Returns the blue channel of a color, ie the component at index `2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![0.1, 0.5, 0.9, 1.0].b(), 0.9);
+    /// ```
+    pub fn b(&self) -> T
+    {
+        self.data[2]
+    }
+
+    /// Returns the alpha channel of a color, ie the component at index `3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec4![0.1, 0.5, 0.9, 1.0].a(), 1.0);
+    /// ```
+    pub fn a(&self) -> T
+    {
+        self.data[3]
     }
-}    
\ No newline at end of file
+}
\ No newline at end of file
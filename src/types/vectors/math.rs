@@ -1,5 +1,6 @@
 use super::VecN;
-use crate::types::math::*;
+use crate::types::matrices::MatN;
+use crate::types::{math::*, SimdScalar};
 
 impl<T, const N: usize> ScalarMath<T> for VecN<T, N>
 where
@@ -11,7 +12,7 @@ where
 {
     fn sum_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
 
         for val in result.data.iter_mut()
         {
@@ -22,7 +23,7 @@ where
     }
     fn sub_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();     
+        let mut result = *self;     
         
         for val in result.data.iter_mut()
         {
@@ -33,7 +34,7 @@ where
     }
     fn mul_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
         
         for val in result.data.iter_mut()
         {
@@ -44,7 +45,7 @@ where
     }
     fn div_scalar(&self, value: T) -> Self 
     {
-        let mut result = self.clone();
+        let mut result = *self;
         
         for val in result.data.iter_mut()
         {
@@ -111,37 +112,1208 @@ pub trait VecMath<T>
     /// - This method assumes that the element type `T` can be converted into `f64`.
     fn length(&self) -> T;
 }
+/// The products are accumulated with `Add` starting from `T::default()`, so element types
+/// don't need to implement `Sum`.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// use std::ops::{Add, Div, Mul};
+///
+/// // A numeric type without `Sub` or `Sum`.
+/// #[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// struct Num(f64);
+///
+/// impl Add for Num { type Output = Self; fn add(self, rhs: Self) -> Self { Num(self.0 + rhs.0) } }
+/// impl Mul for Num { type Output = Self; fn mul(self, rhs: Self) -> Self { Num(self.0 * rhs.0) } }
+/// impl Div for Num { type Output = Self; fn div(self, rhs: Self) -> Self { Num(self.0 / rhs.0) } }
+/// impl Sqrrt for Num { fn sqrrt(&self) -> Self { Num(self.0.sqrt()) } }
+///
+/// let a = VecN::from_array(&[Num(3.0), Num(4.0)]);
+/// let b = VecN::from_array(&[Num(2.0), Num(-1.0)]);
+///
+/// assert_eq!(a.dot(&b), Num(2.0));
+/// assert_eq!(a.length(), Num(5.0));
+/// // `Div` is all `normalize` needs on top.
+/// assert_eq!(a.normalize(), VecN::from_array(&[Num(0.6), Num(0.8)]));
+/// ```
 impl<T, const N: usize> VecMath<T> for VecN<T, N>
+where
+    T: Default + Copy + SimdScalar
+        + Sqrrt
+        + std::ops::Add<Output = T>
+        + std::ops::Mul<Output = T>,
+{
+    fn dot(&self, other: &Self) -> T
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(result) = crate::types::simd::dot(self, other)
+        {
+            return result;
+        }
+
+        self.data.iter()
+            .zip(other.data.iter())
+            .fold(T::default(), |acc, (&a, &b)|
+            {
+                acc + a * b
+            })
+    }
+    fn length(&self) -> T
+    {
+        self.euclidean_length()
+    } 
+}
+impl<T, const N: usize> VecN<T, N>
 where
     T: Default + Copy
         + Sqrrt
         + std::ops::Add<Output = T>
-        + std::ops::Sub<Output = T>
-        + std::ops::Mul<Output = T>
-        + std::ops::Div<Output = T>
-        + std::iter::Sum,
+        + std::ops::Mul<Output = T>,
+{
+    /// Computes the Euclidean length, without the `SimdScalar` bound that [`VecMath`] needs for `dot`.
+    fn euclidean_length(&self) -> T
+    {
+        self.data.iter()
+            .fold(T::default(), |acc, &val|
+            {
+                acc + val * val
+            })
+            .sqrrt()
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy,
+{
+    /// Computes the dot product of two `VecN`, accumulating in the wider type `A`.
+    ///
+    /// Each component is converted into `A` before multiplying, so small element types like `i16`
+    /// can be summed in `i64` without overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second vector to compute the dot product with.
+    ///
+    /// # Returns
+    ///
+    /// The dot product as an `A` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec1 = vec3![i16::MAX, i16::MAX, 2];
+    /// let vec2 = vec3![2_i16, 3, -1];
+    ///
+    /// // `vec1.dot(&vec2)` would overflow `i16`.
+    /// assert_eq!(vec1.dot_into::<i64>(&vec2), 5 * i16::MAX as i64 - 2);
+    /// assert_eq!(vec2![1.5_f32, 2.0].dot_into::<f64>(&vec2![2.0, 0.25]), 3.5);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The sum starts from `A::default()`.
+    ///
+    /// # See Also
+    ///
+    /// - [`dot_f64`](VecN::dot_f64): The same with `A = f64`.
+    pub fn dot_into<A>(&self, other: &Self) -> A
+    where
+        A: Default + From<T>
+            + std::ops::Add<Output = A>
+            + std::ops::Mul<Output = A>,
+    {
+        self.data.iter()
+            .zip(other.data.iter())
+            .fold(A::default(), |acc, (&a, &b)|
+            {
+                acc + A::from(a) * A::from(b)
+            })
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy,
+    f64: From<T>,
+{
+    /// Computes the Euclidean length (magnitude) of the vector as a `f64`.
+    ///
+    /// Unlike [`length`](VecMath::length), this works for any element type that converts into `f64`,
+    /// including integers.
+    ///
+    /// # Returns
+    ///
+    /// The Euclidean length of the vector as a `f64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    ///
+    /// assert_eq!(vec2![3, 4].length_f64(), 5.0);
+    /// assert_eq!(vec3![1.0_f32, 2.0, 2.0].length_f64(), 3.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Each component is converted into `f64` before squaring, so integer vectors don't overflow.
+    pub fn length_f64(&self) -> f64
+    {
+        self.data.iter()
+            .map(|&val|
+            {
+                let n = f64::from(val);
+                n*n
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Computes the L<sub>p</sub> norm of the vector, ie `(sum |x|^p)^(1/p)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The order of the norm, `f64::INFINITY` gives the largest absolute component.
+    ///
+    /// # Returns
+    ///
+    /// The norm of the vector as a `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec2![3.0, -4.0];
+    ///
+    /// assert_eq!(vec.norm(2.0), 5.0);
+    /// assert_eq!(vec.norm(1.0), 7.0);
+    /// assert_eq!(vec.norm(f64::INFINITY), 4.0);
+    /// assert!((vec.norm(3.0) - 91.0_f64.cbrt()).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `p = 2` is the same as [`length_f64`](VecN::length_f64), `p = 1` is the Manhattan length
+    ///   and `p = inf` the Chebyshev length.
+    /// - The norm is computed in `f64`.
+    pub fn norm(&self, p: f64) -> f64
+    {
+        let abs = self.data.iter().map(|&val| f64::from(val).abs());
+
+        if p == 1.0
+        {
+            abs.sum()
+        }
+        else if p == 2.0
+        {
+            self.length_f64()
+        }
+        else if p == f64::INFINITY
+        {
+            abs.fold(0.0, f64::max)
+        }
+        else
+        {
+            abs.map(|val| val.powf(p)).sum::<f64>().powf(1.0 / p)
+        }
+    }
+
+    /// Computes the dot product of two `VecN` as a `f64`.
+    ///
+    /// Each component is converted into `f64` before multiplying, so the products and the sum
+    /// can't overflow like they may with [`dot`](VecMath::dot) for integer vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second vector to compute the dot product with.
+    ///
+    /// # Returns
+    ///
+    /// The dot product as a `f64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec1 = vec2![i32::MAX, i32::MAX];
+    /// let vec2 = vec2![2, 2];
+    ///
+    /// // `vec1.dot(&vec2)` would overflow `i32`.
+    /// assert_eq!(vec1.dot_f64(&vec2), 4.0 * i32::MAX as f64);
+    /// ```
+    pub fn dot_f64(&self, other: &Self) -> f64
+    {
+        self.data.iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| f64::from(a) * f64::from(b))
+            .sum()
+    }
+
+    /// Computes the refraction direction of `self` through a surface, following Snell's law.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The unit normal of the surface, pointing against `self`.
+    /// * `eta` - The ratio between the refractive indices of the two media, ie `n1 / n2`.
+    ///
+    /// # Returns
+    ///
+    /// The refracted direction, or `None` on total internal reflection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// // 45 degrees from air into glass.
+    /// let incident = vec2![1.0, -1.0].normalize();
+    /// let normal = vec2![0.0, 1.0];
+    /// let refracted = incident.refract(&normal, 1.0 / 1.5).unwrap();
+    ///
+    /// let sin_t = (1.0 / 1.5) * to_radians(45.0).sin();
+    /// assert!((refracted[0] - sin_t).abs() < 1e-10);
+    /// assert!((refracted[1] + (1.0 - sin_t * sin_t).sqrt()).abs() < 1e-10);
+    ///
+    /// // 45 degrees from glass into air is past the critical angle.
+    /// assert_eq!(incident.refract(&normal, 1.5), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Both `self` and `normal` are expected to be unit vectors.
+    /// - The result is computed in `f64` and converted back into `T`.
+    /// - The discriminant used is `k = 1 - eta^2 * (1 - dot(normal, self)^2)`, `None` is returned when `k < 0`.
+    pub fn refract(&self, normal: &Self, eta: f64) -> Option<Self>
+    where
+        T: FromF64,
+    {
+        let cos = self.dot_f64(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos * cos);
+
+        if k < 0.0
+        {
+            return None;
+        }
+
+        let factor = eta * cos + k.sqrt();
+        let mut result = *self;
+
+        for (val, n) in result.data.iter_mut().zip(normal.data.iter())
+        {
+            *val = T::from_f64(eta * f64::from(*val) - factor * f64::from(*n));
+        }
+
+        Some(result)
+    }
+
+    /// Linearly interpolates between `self` and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to interpolate to.
+    /// * `t` - The interpolation factor, `0.0` returns `self` and `1.0` returns `other`.
+    ///
+    /// # Returns
+    ///
+    /// A new vector equal to `self + (other - self) * t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec2![0.0, 10.0];
+    /// let b = vec2![10.0, 20.0];
+    ///
+    /// assert_eq!(a.lerp(&b, 0.25), vec2![2.5, 12.5]);
+    /// assert_eq!(vec2![0, 10].lerp(&vec2![10, 20], 0.5), vec2![5, 15]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `t` is not clamped, values outside of `[0, 1]` extrapolate, use [`VecN::lerp_clamped`] to
+    ///   stop at `self` and `other`.
+    /// - The interpolation is computed in `f64` and converted back into `T`.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let mut result = *self;
+
+        for (val, other) in result.data.iter_mut().zip(other.data.iter())
+        {
+            let a = f64::from(*val);
+            *val = T::from_f64(a + (f64::from(*other) - a) * t);
+        }
+
+        result
+    }
+
+    /// Linearly interpolates between `self` and `other`, clamping `t` into `[0, 1]` first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to interpolate to.
+    /// * `t` - The interpolation factor, values below `0.0` return `self` and above `1.0` return `other`.
+    ///
+    /// # Returns
+    ///
+    /// A new vector between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec2![0.0, 10.0];
+    /// let b = vec2![10.0, 20.0];
+    ///
+    /// assert_eq!(a.lerp_clamped(&b, 0.25), a.lerp(&b, 0.25));
+    /// assert_eq!(a.lerp_clamped(&b, 2.0), b);
+    /// assert_eq!(a.lerp_clamped(&b, -1.0), a);
+    /// assert_eq!(a.lerp(&b, 2.0), vec2![20.0, 30.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - [`VecN::lerp`] is the unclamped variant.
+    pub fn lerp_clamped(&self, other: &Self, t: f64) -> Self
+    where
+        T: FromF64,
+    {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Clamps every element of the vector into `[0, 1]`, like colors and blend weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![-0.5, 0.5, 1.5].saturate(), vec3![0.0, 0.5, 1.0]);
+    /// assert_eq!(vec4![2.0_f32, 0.25, -1.0, 1.0].saturate(), vec4![1.0, 0.25, 0.0, 1.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `NaN` elements are propagated, like in the free function [`saturate`](crate::saturate).
+    /// - The clamping is computed in `f64` and converted back into `T`.
+    pub fn saturate(&self) -> Self
+    where
+        T: FromF64,
+    {
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = T::from_f64(crate::utils::saturate(f64::from(*val)));
+        }
+
+        result
+    }
+
+    /// Copies the sign of each element of `signs` onto the magnitude of the matching element of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `signs` - The vector to take the signs from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![3.0, 4.0].copysign(&vec2![-1.0, 1.0]), vec2![-3.0, 4.0]);
+    /// assert_eq!(vec3![-2.0, 5.0, 1.0].copysign(&vec3![0.0, -0.0, -7.0]), vec3![2.0, -5.0, -1.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Meant for float types, the sign of `0.0` and `-0.0` is copied too, like in [`f64::copysign`].
+    /// - The result is computed in `f64` and converted back into `T`.
+    pub fn copysign(&self, signs: &Self) -> Self
+    where
+        T: FromF64,
+    {
+        let mut result = *self;
+
+        for (val, sign) in result.data.iter_mut().zip(signs.data.iter())
+        {
+            *val = T::from_f64(f64::from(*val).copysign(f64::from(*sign)));
+        }
+
+        result
+    }
+
+    /// Spherically interpolates between the directions `self` and `other`.
+    ///
+    /// The result moves along the great-circle arc between both vectors at a constant angular speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The direction to interpolate to.
+    /// * `t` - The interpolation factor, `0.0` returns `self` and `1.0` returns `other`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let x = vec3![1.0, 0.0, 0.0];
+    /// let y = vec3![0.0, 1.0, 0.0];
+    /// let half = x.slerp(&y, 0.5);
+    /// let expected = to_radians(45.0).cos();
+    ///
+    /// assert!((half[0] - expected).abs() < 1e-10);
+    /// assert!((half[1] - expected).abs() < 1e-10);
+    /// assert!((half.length() - 1.0).abs() < 1e-10);
+    ///
+    /// // Opposite directions, the arc goes through a direction perpendicular to both.
+    /// let minus_x = vec3![-1.0, 0.0, 0.0];
+    /// let half = x.slerp(&minus_x, 0.5);
+    /// assert!((half.length() - 1.0).abs() < 1e-10);
+    /// assert!(half.dot(&x).abs() < 1e-10);
+    /// assert!((x.slerp(&minus_x, 1.0) - minus_x).length() < 1e-10);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Both `self` and `other` are expected to be unit vectors.
+    /// - When the vectors are nearly parallel, `sin(angle)` gets too small to divide by, so
+    ///   [`lerp`](VecN::lerp) is used instead.
+    /// - When the vectors are nearly opposite, every great circle through them is valid, the one
+    ///   going through the perpendicular direction closest to the axis where `self` is smallest is
+    ///   used. With `N == 1` there is no such direction and [`lerp`](VecN::lerp) is used instead.
+    /// - The interpolation is computed in `f64` and converted back into `T`.
+    pub fn slerp(&self, other: &Self, t: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let cos = self.dot_f64(other).clamp(-1.0, 1.0);
+        let angle = cos.acos();
+        let sin = angle.sin();
+
+        if sin.abs() < 1e-6 && cos > 0.0
+        {
+            return self.lerp(other, t);
+        }
+
+        if sin.abs() < 1e-6
+        {
+            // The unit axis where `self` is smallest, minus its projection onto `self`, is perpendicular to it.
+            let mut perpendicular = [0.0; N];
+            let smallest = (0..N)
+                .min_by(|&a, &b| f64::from(self.data[a]).abs().total_cmp(&f64::from(self.data[b]).abs()));
+
+            if let Some(axis) = smallest
+            {
+                let projection = f64::from(self.data[axis]);
+                for (val, this) in perpendicular.iter_mut().zip(self.data.iter())
+                {
+                    *val = -projection * f64::from(*this);
+                }
+                perpendicular[axis] += 1.0;
+            }
+
+            let length = perpendicular.iter().map(|val| val * val).sum::<f64>().sqrt();
+            if length < 1e-6
+            {
+                return self.lerp(other, t);
+            }
+
+            let (sin_t, cos_t) = (t * angle).sin_cos();
+            let mut result = *self;
+
+            for (val, perpendicular) in result.data.iter_mut().zip(perpendicular.iter())
+            {
+                *val = T::from_f64(f64::from(*val) * cos_t + perpendicular / length * sin_t);
+            }
+
+            return result;
+        }
+
+        let weight_self = ((1.0 - t) * angle).sin() / sin;
+        let weight_other = (t * angle).sin() / sin;
+        let mut result = *self;
+
+        for (val, other) in result.data.iter_mut().zip(other.data.iter())
+        {
+            *val = T::from_f64(f64::from(*val) * weight_self + f64::from(*other) * weight_other);
+        }
+
+        result
+    }
+
+    /// Rounds every element of the vector to `decimals` decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimal places to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new vector with the rounded elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![1.23456, 2.34567].round_to(2), vec2![1.23, 2.35]);
+    /// assert_eq!(vec2![0.1 + 0.2, 1.0 / 3.0].round_to(4), vec2![0.3, 0.3333]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Halfway cases are rounded away from zero, like [`f64::round`].
+    /// - The rounding is computed in `f64` and converted back into `T`.
+    pub fn round_to(&self, decimals: u32) -> Self
+    where
+        T: FromF64,
+    {
+        let scale = 10f64.powi(decimals as i32);
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = T::from_f64((f64::from(*val) * scale).round() / scale);
+        }
+
+        result
+    }
+
+    /// Scales every element of the vector by the `f64` factor `scale`.
+    fn scale_f64(&self, scale: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = T::from_f64(f64::from(*val) * scale);
+        }
+
+        result
+    }
+
+    /// Limits the length of the vector to `max`, keeping its direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum length of the result.
+    ///
+    /// # Returns
+    ///
+    /// The vector scaled down to length `max` if it is longer, or `self` unchanged otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let velocity = vec2![3.0_f64, 4.0];
+    /// let capped = velocity.clamp_length_max(1.0);
+    ///
+    /// assert!((capped.length() - 1.0).abs() < 1e-10);
+    /// assert!((capped[0] - 0.6).abs() < 1e-10 && (capped[1] - 0.8).abs() < 1e-10);
+    /// assert_eq!(velocity.clamp_length_max(10.0), velocity);
+    /// assert_eq!(vec2![0.0, 0.0].clamp_length_max(1.0), vec2![0.0, 0.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The scaling is computed in `f64` and converted back into `T`.
+    pub fn clamp_length_max(&self, max: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let length = self.length_f64();
+
+        if length <= max || length == 0.0
+        {
+            return *self;
+        }
+
+        self.scale_f64(max / length)
+    }
+
+    /// Makes the length of the vector at least `min`, keeping its direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum length of the result.
+    ///
+    /// # Returns
+    ///
+    /// The vector scaled up to length `min` if it is shorter, or `self` unchanged otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let velocity = vec2![0.3, 0.4];
+    ///
+    /// assert_eq!(velocity.clamp_length_min(5.0), vec2![3.0, 4.0]);
+    /// assert_eq!(velocity.clamp_length_min(0.1), velocity);
+    /// assert_eq!(vec2![0.0, 0.0].clamp_length_min(1.0), vec2![0.0, 0.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The zero vector has no direction, so it is returned unchanged.
+    /// - The scaling is computed in `f64` and converted back into `T`.
+    pub fn clamp_length_min(&self, min: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let length = self.length_f64();
+
+        if length >= min || length == 0.0
+        {
+            return *self;
+        }
+
+        self.scale_f64(min / length)
+    }
+
+    /// Normalizes the vector, or returns `None` if it is too short to have a direction.
+    ///
+    /// # Returns
+    ///
+    /// The normalized vector, or `None` if the length is smaller than `1e-12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec3![0.0, 0.0, 2.0].try_normalize(), Some(vec3![0.0, 0.0, 1.0]));
+    /// assert_eq!(vec3![1.0, 0.0, 0.0].try_normalize(), Some(vec3![1.0, 0.0, 0.0]));
+    /// assert_eq!(vec3![0.0, 0.0, 0.0].try_normalize(), None);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`try_normalize_eps`](VecN::try_normalize_eps): To choose the smallest accepted length.
+    pub fn try_normalize(&self) -> Option<Self>
+    where
+        T: FromF64,
+    {
+        self.try_normalize_eps(1e-12)
+    }
+
+    /// Normalizes the vector, or returns `None` if its length is smaller than `eps`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` - The smallest length considered to have a direction.
+    ///
+    /// # Returns
+    ///
+    /// The normalized vector, or `None` if the length is smaller than `eps`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec2![0.0, 0.001];
+    ///
+    /// assert_eq!(vec.try_normalize_eps(1e-6), Some(vec2![0.0, 1.0]));
+    /// assert_eq!(vec.try_normalize_eps(0.01), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Vectors with a `NaN` or infinite length also return `None`.
+    /// - The normalization is computed in `f64` and converted back into `T`.
+    pub fn try_normalize_eps(&self, eps: f64) -> Option<Self>
+    where
+        T: FromF64,
+    {
+        let length = self.length_f64();
+
+        if length < eps || !length.is_finite()
+        {
+            return None;
+        }
+
+        Some(self.scale_f64(1.0 / length))
+    }
+
+    /// Projects the vector onto `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to project onto, it doesn't need to be normalized.
+    ///
+    /// # Returns
+    ///
+    /// The component of `self` parallel to `other`, or the zero vector if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let vec = vec2![3.0, 4.0];
+    ///
+    /// assert_eq!(vec.project_onto(&vec2![2.0, 0.0]), vec2![3.0, 0.0]);
+    /// assert_eq!(vec.project_onto(&vec2![0.0, 0.0]), vec2![0.0, 0.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The projection is computed in `f64` and converted back into `T`.
+    pub fn project_onto(&self, other: &Self) -> Self
+    where
+        T: FromF64,
+    {
+        let length_squared = other.dot_f64(other);
+
+        if length_squared == 0.0
+        {
+            return Self::new();
+        }
+
+        other.scale_f64(self.dot_f64(other) / length_squared)
+    }
+
+    /// Projects the vector onto the plane through the origin with the given `normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The normal of the plane, it doesn't need to be normalized.
+    ///
+    /// # Returns
+    ///
+    /// The component of `self` lying in the plane, ie `self - self.project_onto(normal)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let up = vec3![0.0, 1.0, 0.0];
+    ///
+    /// assert_eq!(vec3![1.0, 1.0, 0.0].project_onto_plane(&up), vec3![1.0, 0.0, 0.0]);
+    /// assert_eq!(vec3![2.0, -3.0, 4.0].project_onto_plane(&up.mul_scalar(5.0)), vec3![2.0, 0.0, 4.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The normal is used through [`project_onto`](VecN::project_onto), which divides by its
+    ///   squared length, so a unit normal is not required. A zero normal returns `self` unchanged.
+    /// - The projection is computed in `f64` and converted back into `T`.
+    pub fn project_onto_plane(&self, normal: &Self) -> Self
+    where
+        T: FromF64,
+    {
+        let projection = self.project_onto(normal);
+        let mut result = *self;
+
+        for (val, other) in result.data.iter_mut().zip(projection.data.iter())
+        {
+            *val = T::from_f64(f64::from(*val) - f64::from(*other));
+        }
+
+        result
+    }
+
+    /// Bounces the velocity `self` off a surface with the given `normal`.
+    ///
+    /// The velocity is split with [`project_onto`](VecN::project_onto) into a part along the normal
+    /// and a tangential part, the normal part is reflected and scaled by `restitution`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The normal of the surface, it doesn't need to be normalized.
+    /// * `restitution` - How much of the normal speed is kept, `1.0` is a perfect reflection
+    ///   and `0.0` makes the vector slide along the surface.
+    ///
+    /// # Returns
+    ///
+    /// The velocity after the bounce.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let floor = vec3![0.0, 1.0, 0.0];
+    /// let velocity = vec3![2.0, -4.0, 1.0];
+    ///
+    /// assert_eq!(velocity.bounce(&floor, 0.5), vec3![2.0, 2.0, 1.0]);
+    /// assert_eq!(velocity.bounce(&floor, 1.0), vec3![2.0, 4.0, 1.0]);
+    /// assert_eq!(velocity.bounce(&floor, 0.0), vec3![2.0, 0.0, 1.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The bounce is computed in `f64` and converted back into `T`.
+    pub fn bounce(&self, normal: &Self, restitution: f64) -> Self
+    where
+        T: FromF64,
+    {
+        let projection = self.project_onto(normal);
+        let mut result = *self;
+
+        for (val, other) in result.data.iter_mut().zip(projection.data.iter())
+        {
+            *val = T::from_f64(f64::from(*val) - f64::from(*other) * (1.0 + restitution));
+        }
+
+        result
+    }
+
+    /// Computes the shortest distance from the point `self` to the line segment `ab`.
+    ///
+    /// The point is projected onto the line through `a` and `b`, and the projection is clamped
+    /// to the segment before measuring the distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The start of the segment.
+    /// * `b` - The end of the segment.
+    ///
+    /// # Returns
+    ///
+    /// The distance as a `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec2![0, 0];
+    /// let b = vec2![4, 0];
+    ///
+    /// // Above the middle of the segment, the perpendicular distance.
+    /// assert_eq!(vec2![2, 3].distance_to_segment(&a, &b), 3.0);
+    /// // Past the end of the segment, the distance to `b`.
+    /// assert_eq!(vec2![7, 4].distance_to_segment(&a, &b), 5.0);
+    /// // A degenerate segment is a point.
+    /// assert_eq!(vec2![3, 4].distance_to_segment(&a, &a), 5.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The distance is computed in `f64`.
+    pub fn distance_to_segment(&self, a: &Self, b: &Self) -> f64
+    {
+        let mut ab = [0.0; N];
+        let mut ap = [0.0; N];
+
+        for (i, (ab, ap)) in ab.iter_mut().zip(ap.iter_mut()).enumerate()
+        {
+            *ab = f64::from(b[i]) - f64::from(a[i]);
+            *ap = f64::from(self[i]) - f64::from(a[i]);
+        }
+
+        let length_squared = ab.iter().map(|val| val * val).sum::<f64>();
+        let t = if length_squared == 0.0
+        {
+            0.0
+        }
+        else
+        {
+            (ab.iter().zip(ap.iter()).map(|(ab, ap)| ab * ap).sum::<f64>() / length_squared).clamp(0.0, 1.0)
+        };
+
+        ab.iter()
+            .zip(ap.iter())
+            .map(|(ab, ap)|
+            {
+                let diff = ap - ab * t;
+                diff * diff
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy + PartialOrd
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>,
 {
-    fn dot(&self, other: &Self) -> T
+    /// Computes the Manhattan (L1) distance between two `VecN`.
+    ///
+    /// The Manhattan distance is the sum of the absolute differences of their corresponding components.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to compute the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The Manhattan distance as a `T` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![0, 0].distance_manhattan(&vec2![3, 4]), 7);
+    /// assert_eq!(vec3![1.0, -1.0, 0.5].distance_manhattan(&vec3![-1.0, 1.0, 0.5]), 4.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The absolute difference is computed by subtracting the smaller component from the bigger one,
+    ///   so unsigned element types are supported.
+    pub fn distance_manhattan(&self, other: &Self) -> T
     {
         self.data.iter()
             .zip(other.data.iter())
-            .map(|(&a, &b)|
+            .fold(T::default(), |acc, (&a, &b)|
             {
-                a * b
+                acc + if a > b { a - b } else { b - a }
             })
-            .sum()
     }
-    fn length(&self) -> T
+
+    /// Computes the Chebyshev (L∞) distance between two `VecN`.
+    ///
+    /// The Chebyshev distance is the biggest absolute difference of their corresponding components.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector to compute the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The Chebyshev distance as a `T` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![0, 0].distance_chebyshev(&vec2![3, 4]), 4);
+    /// assert_eq!(vec3![1u32, 8, 2].distance_chebyshev(&vec3![4, 2, 2]), 6);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - The absolute difference is computed by subtracting the smaller component from the bigger one,
+    ///   so unsigned element types are supported.
+    pub fn distance_chebyshev(&self, other: &Self) -> T
     {
         self.data.iter()
-            .map(|&val|
+            .zip(other.data.iter())
+            .fold(T::default(), |acc, (&a, &b)|
             {
-                let n = val;
-                n*n
-            }) 
-            .sum::<T>()
-            .sqrrt()
-    } 
+                let diff = if a > b { a - b } else { b - a };
+
+                if diff > acc { diff } else { acc }
+            })
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy
+        + std::ops::Mul<Output = T>,
+{
+    /// Computes the outer product of two `VecN`.
+    ///
+    /// The outer product is the matrix where each element is `self[i] * other[j]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second vector to compute the outer product with.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatN` with the outer product of the two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let mat = vec2![1, 2].outer(&vec2![3, 4]);
+    ///
+    /// assert_eq!(mat.to_mat(), [[3, 4], [6, 8]]);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`MatN`]: The matrix type returned by this method.
+    pub fn outer(&self, other: &Self) -> MatN<T, N>
+    {
+        let mut result = MatN::new();
+
+        for (row, &a) in result.iter_mut().zip(self.data.iter())
+        {
+            for (val, &b) in row.iter_mut().zip(other.data.iter())
+            {
+                *val = a * b;
+            }
+        }
+
+        result
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy + Fma,
+{
+    /// Computes `self * mul + add` for each element, with a single rounding per element.
+    ///
+    /// # Arguments
+    ///
+    /// * `mul` - The vector to multiply `self` by.
+    /// * `add` - The vector added to the product.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` with the fused multiply-add of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// let a = vec3![1.5_f64, -2.0, 0.1];
+    /// let b = vec3![2.0, 3.0, 0.1];
+    /// let c = vec3![0.5, 1.0, -0.01];
+    ///
+    /// for (fused, naive) in a.mul_add(&b, &c).iter().zip((a * b + c).iter())
+    /// {
+    ///     assert!((fused - naive).abs() < 1e-12);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Uses `mul_add` of the float type, which is more accurate than `self * mul + add`,
+    ///   and faster on targets with hardware FMA support.
+    pub fn mul_add(&self, mul: &Self, add: &Self) -> Self
+    {
+        let mut result = *self;
+
+        for ((val, mul), add) in result.data.iter_mut().zip(mul.data.iter()).zip(add.data.iter())
+        {
+            *val = val.mul_addd(*mul, *add);
+        }
+
+        result
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy + FloatPow,
+{
+    /// Raises each element of the vector to the floating point power `exp`.
+    ///
+    /// # Arguments
+    ///
+    /// * `exp` - The exponent.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` where each element is `self[i]^exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![2.0, 3.0].powf(2.0), vec2![4.0, 9.0]);
+    ///
+    /// // Gamma correction.
+    /// let linear = vec3![0.25_f32, 0.0, 1.0];
+    /// assert_eq!(linear.powf(0.5), vec3![0.5, 0.0, 1.0]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Negative elements raised to a fractional exponent result in `NaN`.
+    pub fn powf(&self, exp: f64) -> Self
+    {
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = val.powff(exp);
+        }
+
+        result
+    }
+}
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Default + Copy + UnitValue
+        + std::ops::Div<Output = T>,
+{
+    /// Computes the reciprocal `1 / x` of each element of the vector.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` where each element is `1 / self[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![2.0, 4.0].recip(), vec2![0.5, 0.25]);
+    /// assert_eq!(vec2![0.0, -0.0].recip(), vec2![f64::INFINITY, f64::NEG_INFINITY]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - For float types, zero elements result in an infinity with the same sign as the zero.
+    /// - Meant for float types, integer types truncate the result and panic on zero elements.
+    pub fn recip(&self) -> Self
+    {
+        let mut result = *self;
+
+        for val in result.data.iter_mut()
+        {
+            *val = T::unit_value() / *val;
+        }
+
+        result
+    }
+
+    /// Raises each element of the vector to the integer power `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The exponent, a negative exponent computes the reciprocal of the positive power.
+    ///
+    /// # Returns
+    ///
+    /// A new `VecN` where each element is `self[i]^n`, or the ones vector when `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vmm::*;
+    /// assert_eq!(vec2![2, 3].powi(2), vec2![4, 9]);
+    /// assert_eq!(vec2![2, 3].powi(0), vec2![1, 1]);
+    /// assert_eq!(vec3![2.0, -3.0, 0.5].powi(3), vec3![8.0, -27.0, 0.125]);
+    /// assert_eq!(vec2![2.0, 4.0].powi(-2), vec2![0.25, 0.0625]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Uses exponentiation by squaring, so only `O(log n)` multiplications are made per element.
+    /// - Works for integer types with non-negative exponents, and overflows like repeated `*` does.
+    ///   Negative exponents are meant for float types, see [`recip`](VecN::recip).
+    pub fn powi(&self, n: i32) -> Self
+    where
+        T: std::ops::Mul<Output = T>,
+    {
+        let mut result = Self::new_with(T::unit_value());
+        let mut base = *self;
+        let mut exp = n.unsigned_abs();
+
+        while exp > 0
+        {
+            if exp & 1 == 1
+            {
+                result = result.zip_with(&base, |a, b| a * b);
+            }
+
+            exp >>= 1;
+            if exp > 0
+            {
+                base = base.zip_with(&base, |a, b| a * b);
+            }
+        }
+
+        if n < 0 { result.recip() } else { result }
+    }
 }
 pub trait Normalize 
 {
@@ -175,20 +1347,29 @@ pub trait Normalize
     /// - [`length`](super::VecN::length): Method to compute the Euclidean length of the vector.
     fn normalize(&self) -> Self;    
 }
+/// Only the operations used by [`length`](VecMath::length) and the division are required, so
+/// minimal numeric types can be normalized too, see the custom element type example on the
+/// [`VecMath`] implementation of `VecN`.
+///
+/// # Examples
+///
+/// ```
+/// # use vmm::*;
+/// assert_eq!(vec3![0.0_f32, 3.0, 4.0].normalize(), vec3![0.0, 0.6, 0.8]);
+/// assert_eq!(vec2![3.0_f64, 4.0].normalize(), vec2![0.6, 0.8]);
+/// ```
 impl<T, const N: usize> Normalize for VecN<T, N> 
 where
     T: Default + Copy
         + Sqrrt
         + std::ops::Add<Output = T>
-        + std::ops::Sub<Output = T>
         + std::ops::Mul<Output = T>
-        + std::ops::Div<Output = T>
-        + std::iter::Sum,
+        + std::ops::Div<Output = T>,
 {
     fn normalize(&self) -> Self 
     {
-        let len = self.length();     
-        let mut result = self.clone();
+        let len = self.euclidean_length();     
+        let mut result = *self;
 
         for val in result.data.iter_mut()
         {
@@ -20,7 +20,7 @@
 /// ```
 pub fn to_radians(value: f64) -> f64
 {
-    value * 0.01745329251994329576923690768489
+    value * 0.017453292519943295
 }
 /// Converts an angle from radians to degrees.
 ///
@@ -44,5 +44,240 @@ pub fn to_radians(value: f64) -> f64
 /// ```
 pub fn to_degrees(value: f64) -> f64
 {
-    value * 57.295779513082320876798154814105
+    value * 57.29577951308232
+}
+/// Linearly interpolates between `a` and `b`.
+///
+/// # Arguments
+///
+/// * `a` - The value returned when `t` is `0.0`.
+/// * `b` - The value returned when `t` is `1.0`.
+/// * `t` - The interpolation factor, it is not clamped.
+///
+/// # Returns
+///
+/// The value `a + (b - a) * t`.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(lerp(10.0, 20.0, 0.25), 12.5);
+/// assert_eq!(lerp(10.0, 20.0, 2.0), 30.0);
+/// ```
+pub fn lerp(a: f64, b: f64, t: f64) -> f64
+{
+    a + (b - a) * t
+}
+/// Finds the interpolation factor of `value` between `a` and `b`, the inverse of [`lerp`].
+///
+/// # Arguments
+///
+/// * `a` - The value mapped to `0.0`.
+/// * `b` - The value mapped to `1.0`.
+/// * `value` - The value to find the factor of.
+///
+/// # Returns
+///
+/// The factor `t` such that `lerp(a, b, t) == value`, or `0.0` when `a == b`.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(inverse_lerp(10.0, 20.0, 12.5), 0.25);
+/// assert_eq!(inverse_lerp(5.0, 5.0, 7.0), 0.0);
+/// ```
+pub fn inverse_lerp(a: f64, b: f64, value: f64) -> f64
+{
+    if a == b
+    {
+        return 0.0;
+    }
+
+    (value - a) / (b - a)
+}
+/// Maps `value` from the range `[in_min, in_max]` into the range `[out_min, out_max]`.
+///
+/// # Arguments
+///
+/// * `value` - The value to remap.
+/// * `in_min`, `in_max` - The input range.
+/// * `out_min`, `out_max` - The output range.
+///
+/// # Returns
+///
+/// The remapped value, it is not clamped to the output range.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(remap(5.0, 0.0, 10.0, 100.0, 200.0), 150.0);
+/// assert_eq!(remap(0.25, 0.0, 1.0, -1.0, 1.0), -0.5);
+/// ```
+pub fn remap(value: f64, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> f64
+{
+    lerp(out_min, out_max, inverse_lerp(in_min, in_max, value))
+}
+/// Smoothly interpolates from `0.0` to `1.0` as `x` goes from `edge0` to `edge1`.
+///
+/// # Arguments
+///
+/// * `edge0` - The value of `x` where the result starts to rise from `0.0`.
+/// * `edge1` - The value of `x` where the result reaches `1.0`.
+/// * `x` - The value to evaluate.
+///
+/// # Returns
+///
+/// The Hermite interpolation `t * t * (3 - 2 * t)`, where `t` is `x` clamped into `[0, 1]`
+/// relative to the edges.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(smoothstep(0.0, 1.0, 0.0), 0.0);
+/// assert_eq!(smoothstep(0.0, 1.0, 1.0), 1.0);
+/// assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smoothstep(2.0, 4.0, -3.0), 0.0);
+/// assert_eq!(smoothstep(2.0, 4.0, 9.0), 1.0);
+/// ```
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64
+{
+    let t = inverse_lerp(edge0, edge1, x).clamp(0.0, 1.0);
+
+    t * t * (3.0 - 2.0 * t)
+}
+/// Restricts `value` to the range `[min, max]`.
+///
+/// # Arguments
+///
+/// * `value` - The value to clamp.
+/// * `min` - The lower bound.
+/// * `max` - The upper bound.
+///
+/// # Returns
+///
+/// `min` if `value` is smaller than it, `max` if `value` is bigger than it, `value` otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(clamp(5.0, 0.0, 2.0), 2.0);
+/// assert_eq!(clamp(-1.0, 0.0, 2.0), 0.0);
+/// assert!(clamp(f64::NAN, 0.0, 2.0).is_nan());
+/// ```
+///
+/// # Notes
+///
+/// - `NaN` is propagated, not clamped.
+/// - Unlike [`f64::clamp`], this doesn't panic when `min > max`, `min` is checked first.
+pub fn clamp(value: f64, min: f64, max: f64) -> f64
+{
+    if value < min
+    {
+        min
+    }
+    else if value > max
+    {
+        max
+    }
+    else
+    {
+        value
+    }
+}
+/// Restricts `value` to the range `[0, 1]`.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(saturate(1.5), 1.0);
+/// assert_eq!(saturate(-0.2), 0.0);
+/// assert_eq!(saturate(0.3), 0.3);
+/// ```
+///
+/// # Notes
+///
+/// - `NaN` is propagated, like in [`clamp`].
+pub fn saturate(value: f64) -> f64
+{
+    clamp(value, 0.0, 1.0)
+}
+/// Wraps `value` into the range `[min, max)`, repeating the range in both directions.
+///
+/// # Arguments
+///
+/// * `value` - The value to wrap.
+/// * `min` - The lower bound, included.
+/// * `max` - The upper bound, excluded.
+///
+/// # Returns
+///
+/// The value in `[min, max)` that differs from `value` by a multiple of `max - min`,
+/// or `min` when the range is empty.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+///
+/// assert_eq!(wrap(370.0, 0.0, 360.0), 10.0);
+/// assert_eq!(wrap(-30.0, 0.0, 360.0), 330.0);
+/// assert_eq!(wrap(360.0, 0.0, 360.0), 0.0);
+///
+/// // `rem_euclid` rounds `360.0 - 1e-17` up to `360.0`, which wraps back to `min`.
+/// assert_eq!(wrap(-1e-17, 0.0, 360.0), 0.0);
+/// ```
+///
+/// # Notes
+///
+/// - Negative values are handled with [`f64::rem_euclid`], not `%`.
+/// - A result that rounds up to `max` is replaced by `min`, so `max` is never returned.
+pub fn wrap(value: f64, min: f64, max: f64) -> f64
+{
+    let range = max - min;
+
+    if range <= 0.0
+    {
+        return min;
+    }
+
+    let wrapped = min + (value - min).rem_euclid(range);
+
+    if wrapped >= max { min } else { wrapped }
+}
+/// Wraps an angle into the range `[-PI, PI)`.
+///
+/// # Arguments
+///
+/// * `radians` - The angle in radians.
+///
+/// # Returns
+///
+/// The equivalent angle in `[-PI, PI)`.
+///
+/// # Example
+///
+/// ```
+/// # use vmm::*;
+/// use std::f64::consts::PI;
+///
+/// assert!((wrap_angle(3.0 * PI) + PI).abs() < 1e-10);
+/// assert!((wrap_angle(-PI / 2.0 - 4.0 * PI) + PI / 2.0).abs() < 1e-10);
+/// assert_eq!(wrap_angle(1.0), 1.0);
+/// assert_eq!(wrap_angle((-PI).next_down()), -PI);
+/// ```
+pub fn wrap_angle(radians: f64) -> f64
+{
+    wrap(radians, -std::f64::consts::PI, std::f64::consts::PI)
 }
\ No newline at end of file